@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use aoc_common::{parse_field, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        // Each line is an integer, so parse it
+        input
+            .split_whitespace()
+            .map(|x| parse_field(input, x, "an integer"))
+            .collect()
+    }
+
+    fn part1(numbers: &Vec<i32>, _: &()) -> impl Display {
+        // Count the number of increases in the file
+        let mut num_increases = 0;
+        for i in 1..numbers.len() {
+            if numbers[i - 1] < numbers[i] {
+                num_increases += 1;
+            }
+        }
+        num_increases
+    }
+
+    fn part2(numbers: &Vec<i32>, _: &()) -> impl Display {
+        // Count the number of increases in the sum of a sliding window of three
+        let mut num_increases = 0;
+        let mut prev_window_sum = -1;
+        for i in 2..numbers.len() {
+            let window_sum = numbers[i - 2] + numbers[i - 1] + numbers[i];
+            if prev_window_sum >= 0 && window_sum > prev_window_sum {
+                num_increases += 1;
+            }
+            prev_window_sum = window_sum;
+        }
+        num_increases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        // Every depth is parsed back as it was written
+        #[test]
+        fn round_trip(depths in prop::collection::vec(0..10000i32, 1..50)) {
+            let input = depths
+                .iter()
+                .map(|depth| depth.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+            prop_assert_eq!(Day01::parse(&input).unwrap(), depths);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[0-9a\n é-]{0,40}") {
+            let _ = Day01::parse(&input);
+        }
+    }
+}
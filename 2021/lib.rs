@@ -0,0 +1,14 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Solutions for Advent of Code 2021.
+
+use aoc_common::Day;
+
+#[path = "day-1/mod.rs"]
+pub mod day01;
+
+/// Every solution in this crate, keyed by day.
+pub const SOLUTIONS: &[Day] = &[Day::new::<day01::Day01>(1)];
@@ -0,0 +1,102 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{parse_field, Generator, ParseError, Rng, Solution};
+use rand::Rng as _;
+
+pub struct Day01;
+
+// Write `size` elves, each carrying a few snacks. Part 2 needs at least three.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(3))
+        .map(|_| {
+            (0..rng.random_range(1..=15))
+                .map(|_| rng.random_range(1000..=60000).to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+        + "\n"
+}
+
+impl Solution for Day01 {
+    type Input = Vec<usize>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        // Split the file based on pairs of newline characters. Each group of
+        // lines has a list of integers separated by newlines, so we sum each
+        // group as we go.
+        let sums = input
+            .split("\n\n")
+            .map(|group| {
+                group.split_whitespace().try_fold(0usize, |sum, line| {
+                    parse_field::<usize>(input, line, "an integer")?
+                        .checked_add(sum)
+                        .ok_or_else(|| ParseError::at(input, line, "a smaller total"))
+                })
+            })
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        // Part 2 adds up the top three elves
+        if sums.len() < 3 {
+            return Err(ParseError::after(input, input.trim_end(), "another elf"));
+        }
+        Ok(sums)
+    }
+
+    fn part1(sums: &Vec<usize>, _: &()) -> impl Display {
+        // The sum of the largest group
+        *sums.iter().max().unwrap()
+    }
+
+    fn part2(sums: &Vec<usize>, _: &()) -> impl Display {
+        // The sum of the largest three groups
+        let mut sums = sums.clone();
+        sums.sort();
+        sums.reverse();
+        sums[0] + sums[1] + sums[2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Fewer than three elves is rejected at the end of the input
+    #[test]
+    fn too_few_elves() {
+        let input = "1\n\n2\n";
+        let expected = ParseError::after(input, &input[..4], "another elf");
+        assert_eq!(Day01::parse(input), Err(expected));
+    }
+
+    proptest! {
+        // Each elf's snacks are parsed back to the elf's total
+        #[test]
+        fn round_trip(elves in prop::collection::vec(prop::collection::vec(0..100000usize, 1..10), 3..20)) {
+            let input = elves
+                .iter()
+                .map(|elf| elf.iter().map(|snack| snack.to_string()).collect::<Vec<String>>().join("\n"))
+                .collect::<Vec<String>>()
+                .join("\n\n");
+            let totals = elves.iter().map(|elf| elf.iter().sum()).collect::<Vec<usize>>();
+            prop_assert_eq!(Day01::parse(&input).unwrap(), totals);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[0-9a\n é-]{0,40}") {
+            let _ = Day01::parse(&input);
+        }
+    }
+}
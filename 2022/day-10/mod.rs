@@ -0,0 +1,164 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{debug, parse_field, Generator, ParseError, Rng, Solution};
+use rand::Rng as _;
+
+pub struct Day10;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+// The signal strength is sampled during the 20th cycle, and every 40 cycles after that
+fn is_sample_cycle(cycle_index: i32) -> bool {
+    cycle_index == 20 || (cycle_index > 20 && (cycle_index - 20) % 40 == 0)
+}
+
+// Get the register value during every cycle of the program
+fn register_values(ops: &[Op]) -> Vec<i32> {
+    let mut register_value = 1;
+    let mut values = Vec::new();
+    for op in ops {
+        match op {
+            Op::Addx(arg) => {
+                // Takes two cycles to execute
+                values.push(register_value);
+                values.push(register_value);
+                // Add the argument to the register
+                register_value += arg;
+            }
+            Op::Noop => {
+                // Takes one cycle to execute
+                values.push(register_value);
+            }
+        }
+    }
+    values
+}
+
+// Write `size` instructions, or as many as it takes to draw the whole screen. The
+// register stays on the screen, so the sprite is always drawn somewhere.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    let mut register = 1;
+    let mut cycles = 0;
+    let mut count = 0;
+    while count < size || cycles < 240 {
+        if rng.random_bool(0.4) {
+            output += "noop\n";
+            cycles += 1;
+        } else {
+            let value = rng.random_range((-register).max(-20)..=(39 - register).min(20));
+            register += value;
+            output += &format!("addx {}\n", value);
+            cycles += 2;
+        }
+        count += 1;
+    }
+    output
+}
+
+impl Solution for Day10 {
+    type Input = Vec<Op>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<Op>, ParseError> {
+        let mut ops = Vec::new();
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let op = line.split(' ').next().unwrap();
+            match op {
+                "addx" => {
+                    // Parse the argument
+                    let arg = line
+                        .split(' ')
+                        .nth(1)
+                        .ok_or_else(|| ParseError::after(input, line, "an argument"))?;
+                    ops.push(Op::Addx(parse_field(input, arg, "an integer")?));
+                }
+                "noop" => ops.push(Op::Noop),
+                _ => return Err(ParseError::at(input, op, "`addx` or `noop`")),
+            }
+        }
+        Ok(ops)
+    }
+
+    fn part1(ops: &Vec<Op>, _: &()) -> impl Display {
+        let mut signal_strength = 0;
+        for (i, register_value) in register_values(ops).into_iter().enumerate() {
+            let cycle_index = i as i32 + 1;
+            if is_sample_cycle(cycle_index) {
+                // Add to the signal strength
+                signal_strength += cycle_index * register_value;
+                debug!(
+                    "Cycle {}: strength: {}, rval: {}",
+                    cycle_index, signal_strength, register_value
+                );
+            }
+        }
+        signal_strength
+    }
+
+    fn part2(ops: &Vec<Op>, _: &()) -> impl Display {
+        // The sprite is three pixels wide, so the pixel is lit if the register
+        // is within one of the column being drawn
+        let mut pixels = Vec::new();
+        for register_value in register_values(ops) {
+            if ((pixels.len() as i32 % 40) - register_value).abs() < 2 {
+                pixels.push('#');
+            } else {
+                pixels.push('.');
+            }
+        }
+
+        // Render the pixels as a 40-wide image
+        pixels
+            .chunks(40)
+            .filter(|row| row.len() == 40)
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // An instruction
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![Just(Op::Noop), (-100..100i32).prop_map(Op::Addx)]
+    }
+
+    proptest! {
+        // Every instruction is parsed back as it was written
+        #[test]
+        fn round_trip(ops in prop::collection::vec(op(), 1..50)) {
+            let input = ops
+                .iter()
+                .map(|op| match op {
+                    Op::Noop => "noop".to_string(),
+                    Op::Addx(value) => format!("addx {}", value),
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            prop_assert_eq!(Day10::parse(&input).unwrap(), ops);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "(addx|noop|[0-9 \né-]){0,20}") {
+            let _ = Day10::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,389 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{params, parse_field, trace, Generator, ParseError, Rng, Solution};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+pub struct Day11;
+
+params! {
+    /// How long the monkeys keep going.
+    pub struct Params {
+        /// Rounds simulated in part 2
+        rounds: usize = 10000,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    inspections: u64,
+    items: Vec<u64>,
+    operation: (char, Option<u64>, Option<u64>),
+    test: u64,
+    true_target: usize,
+    false_target: usize,
+}
+
+impl Monkey {
+    fn new() -> Monkey {
+        Monkey {
+            inspections: 0,
+            items: Vec::new(),
+            operation: ('.', None, None),
+            test: 0,
+            true_target: 0,
+            false_target: 0,
+        }
+    }
+
+    // Apply the monkey's operation to an item's worry level
+    fn inspect(&self, item: u64) -> u64 {
+        match self.operation.0 {
+            '+' => self.operation.1.unwrap_or(item) + self.operation.2.unwrap_or(item),
+            '*' => self.operation.1.unwrap_or(item) * self.operation.2.unwrap_or(item),
+            _ => panic!("Invalid operation"),
+        }
+    }
+}
+
+// Get the nth line of a monkey's block
+fn block_line<'a>(input: &str, lines: &'a str, n: usize) -> Result<&'a str, ParseError> {
+    lines
+        .split('\n')
+        .nth(n)
+        .ok_or_else(|| ParseError::after(input, lines, "another line describing the monkey"))
+}
+
+// Get the last whitespace separated word on the nth line of a block
+fn last_word<'a>(input: &str, lines: &'a str, n: usize) -> Result<&'a str, ParseError> {
+    Ok(block_line(input, lines, n)?
+        .trim()
+        .split(' ')
+        .next_back()
+        .unwrap()
+        .trim())
+}
+
+// The monkey business is the number of inspections of the top two monkeys multiplied together
+fn monkey_business(monkeys: &[Monkey]) -> u64 {
+    let mut monkey_inspections: Vec<u64> = monkeys.iter().map(|m| m.inspections).collect();
+    monkey_inspections.sort();
+    monkey_inspections[monkey_inspections.len() - 1]
+        * monkey_inspections[monkey_inspections.len() - 2]
+}
+
+// Write `size` monkeys, at most nine so each tests a different prime. Worry levels
+// grow far more slowly than in the puzzle, whose squaring and large factors
+// overflow part 1 unless the throws are arranged just so: monkeys multiply by at
+// most 3, which relief divides back out, and one doubles with `old + old`.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 9);
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    primes.shuffle(rng);
+    let doubles = rng.random_range(0..count);
+
+    let mut blocks = Vec::new();
+    for (monkey, &test) in primes.iter().take(count).enumerate() {
+        let items = (0..rng.random_range(1..=8))
+            .map(|_| rng.random_range(50..100).to_string())
+            .collect::<Vec<String>>();
+        let operation = if monkey == doubles {
+            "old + old".to_string()
+        } else if rng.random_bool(0.3) {
+            format!("old * {}", rng.random_range(2..=3))
+        } else {
+            format!("old + {}", rng.random_range(1..9))
+        };
+        // Throw to monkeys other than this one, two different ones if there are enough
+        let true_target = (monkey + rng.random_range(1..count)) % count;
+        let mut false_target = (monkey + rng.random_range(1..count)) % count;
+        while count > 2 && false_target == true_target {
+            false_target = (monkey + rng.random_range(1..count)) % count;
+        }
+        blocks.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            monkey,
+            items.join(", "),
+            operation,
+            test,
+            true_target,
+            false_target
+        ));
+    }
+    blocks.join("\n")
+}
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Params = Params;
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        // Parse the input into a vector of monkeys
+        let mut monkeys: Vec<Monkey> = Vec::new();
+        let mut targets = Vec::new();
+        for lines in input.split("\n\n").filter(|lines| !lines.trim().is_empty()) {
+            // Create a new monkey
+            let mut monkey = Monkey::new();
+            // First line is the monkey's name (We don't care)
+            // Second line is the items
+            let items_line = block_line(input, lines, 1)?;
+            let items = items_line
+                .split(':')
+                .nth(1)
+                .ok_or_else(|| ParseError::after(input, items_line, "`:`"))?;
+            for item in items.split(',') {
+                // Parse the item into a u64
+                monkey
+                    .items
+                    .push(parse_field(input, item.trim(), "a worry level")?);
+            }
+            // Third line is the operation
+            let op_line = block_line(input, lines, 2)?;
+            let op_str = op_line
+                .split('=')
+                .nth(1)
+                .ok_or_else(|| ParseError::after(input, op_line, "`=`"))?
+                .trim();
+            let ops = op_str.split(' ').collect::<Vec<&str>>();
+            if ops.len() != 3 {
+                return Err(ParseError::at(
+                    input,
+                    op_str,
+                    "an operation like `old * 19`",
+                ));
+            }
+            if ops[1] != "+" && ops[1] != "*" {
+                return Err(ParseError::at(input, ops[1], "`+` or `*`"));
+            }
+            // Each operand is either the old worry level or a number
+            let operand = |operand: &str| match operand.trim() {
+                "old" => Ok(None),
+                operand => parse_field(input, operand, "`old` or a number").map(Some),
+            };
+            monkey.operation = (
+                ops[1].chars().next().unwrap(),
+                operand(ops[0])?,
+                operand(ops[2])?,
+            );
+            // Fourth line is the test
+            let test = last_word(input, lines, 3)?;
+            monkey.test = parse_field(input, test, "a divisor")?;
+            if monkey.test == 0 {
+                return Err(ParseError::at(input, test, "a divisor above 0"));
+            }
+            // Fifth line is the true target
+            let true_target = last_word(input, lines, 4)?;
+            monkey.true_target = parse_field(input, true_target, "a monkey number")?;
+            // Sixth line is the false target
+            let false_target = last_word(input, lines, 5)?;
+            monkey.false_target = parse_field(input, false_target, "a monkey number")?;
+            targets.push((monkey.true_target, true_target));
+            targets.push((monkey.false_target, false_target));
+            monkeys.push(monkey);
+        }
+
+        // The monkey business needs the two busiest monkeys
+        if monkeys.len() < 2 {
+            return Err(ParseError::after(input, input.trim_end(), "another monkey"));
+        }
+
+        // Monkeys can only throw to monkeys that exist
+        for (target, field) in targets {
+            if target >= monkeys.len() {
+                let expected = format!("a monkey number below {}", monkeys.len());
+                return Err(ParseError::at(input, field, expected));
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Vec<Monkey>, _: &Params) -> impl Display {
+        let mut monkeys = monkeys.clone();
+
+        let rounds = 20;
+        for _ in 0..rounds {
+            // Simulate the monkeys
+            for m in 0..monkeys.len() {
+                // Apply the operation to each item value
+                let mut new_items = Vec::new();
+                for &item in &monkeys[m].items {
+                    let new_item = monkeys[m].inspect(item);
+                    trace!(
+                        "Monkey {} inspected item {}, worry grew to {}",
+                        m,
+                        item,
+                        new_item
+                    );
+                    new_items.push(new_item);
+                }
+                monkeys[m].inspections += new_items.len() as u64;
+
+                // Decrease the value of each new item by 1/3
+                for item in new_items.iter_mut() {
+                    trace!(
+                        "Monkey {} decreased worry of item {} to {}",
+                        m,
+                        item,
+                        *item / 3
+                    );
+                    *item /= 3;
+                }
+
+                // Test each of the items, and move them to the appropriate target
+                for item in new_items {
+                    if item % monkeys[m].test == 0 {
+                        let target = monkeys[m].true_target;
+                        monkeys[target].items.push(item);
+                        trace!(
+                            "Test True: Monkey {} moved item {} to monkey {}",
+                            m,
+                            item,
+                            target
+                        )
+                    } else {
+                        let target = monkeys[m].false_target;
+                        monkeys[target].items.push(item);
+                        trace!(
+                            "Test False: Monkey {} moved item {} to monkey {}",
+                            m,
+                            item,
+                            target
+                        )
+                    }
+                }
+
+                // Clear the items
+                monkeys[m].items.clear();
+            }
+        }
+
+        monkey_business(&monkeys)
+    }
+
+    fn part2(monkeys: &Vec<Monkey>, params: &Params) -> impl Display {
+        let mut monkeys = monkeys.clone();
+        let monkey_test_prod = monkeys.iter().fold(1, |acc, m| acc * m.test);
+
+        for r in 0..params.rounds {
+            // Simulate the monkeys
+            trace!("Simulating monkeys... Round {}", r);
+            for m in 0..monkeys.len() {
+                // Apply the operation to each item value, then keep the values
+                // small by taking them modulo the product of the monkey tests
+                let new_items: Vec<u64> = monkeys[m]
+                    .items
+                    .iter()
+                    .map(|&item| monkeys[m].inspect(item) % monkey_test_prod)
+                    .collect();
+                monkeys[m].inspections += new_items.len() as u64;
+
+                // Test each of the items, and move them to the appropriate target
+                for item in new_items {
+                    let target = if item % monkeys[m].test == 0 {
+                        monkeys[m].true_target
+                    } else {
+                        monkeys[m].false_target
+                    };
+                    monkeys[target].items.push(item);
+                }
+
+                // Clear the items
+                monkeys[m].items.clear();
+            }
+        }
+
+        monkey_business(&monkeys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Monkeys with their items, operations, tests and targets among each other
+    fn monkeys() -> impl Strategy<Value = Vec<Monkey>> {
+        (2..8usize).prop_flat_map(|count| {
+            let operand = prop::option::of(1..100u64);
+            let monkey = (
+                prop::collection::vec(0..100u64, 1..8),
+                (
+                    prop::sample::select(vec!['+', '*']),
+                    operand.clone(),
+                    operand,
+                ),
+                1..30u64,
+                0..count,
+                0..count,
+            )
+                .prop_map(
+                    |(items, operation, test, true_target, false_target)| Monkey {
+                        inspections: 0,
+                        items,
+                        operation,
+                        test,
+                        true_target,
+                        false_target,
+                    },
+                );
+            prop::collection::vec(monkey, count)
+        })
+    }
+
+    // Write a monkey's block as it appears in the puzzle
+    fn render(index: usize, monkey: &Monkey) -> String {
+        let operand = |operand: Option<u64>| operand.map_or("old".to_string(), |n| n.to_string());
+        let items = monkey
+            .items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<String>>();
+        format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {} {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            index,
+            items.join(", "),
+            operand(monkey.operation.1),
+            monkey.operation.0,
+            operand(monkey.operation.2),
+            monkey.test,
+            monkey.true_target,
+            monkey.false_target
+        )
+    }
+
+    proptest! {
+        // Every monkey is parsed back as it was written
+        #[test]
+        fn round_trip(monkeys in monkeys()) {
+            let input = monkeys
+                .iter()
+                .enumerate()
+                .map(|(index, monkey)| render(index, monkey))
+                .collect::<Vec<String>>()
+                .join("\n");
+            let parsed = Day11::parse(&input).unwrap();
+            prop_assert_eq!(parsed.len(), monkeys.len());
+            for (parsed, monkey) in parsed.iter().zip(&monkeys) {
+                prop_assert_eq!(&parsed.items, &monkey.items);
+                prop_assert_eq!(parsed.operation, monkey.operation);
+                prop_assert_eq!(parsed.test, monkey.test);
+                prop_assert_eq!(parsed.true_target, monkey.true_target);
+                prop_assert_eq!(parsed.false_target, monkey.false_target);
+            }
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "(Monkey|items|old|new|[0-9:,=+* \né]){0,30}") {
+            let _ = Day11::parse(&input);
+        }
+    }
+}
@@ -3,9 +3,16 @@
 # This software is released under the MIT License.
 # https://opensource.org/licenses/MIT
 
+import math
+import sys
 from typing import Tuple, Optional
 from dataclasses import dataclass
-import tqdm
+
+try:
+    from tqdm import tqdm
+except ImportError:
+    def tqdm(iterable):
+        return iterable
 
 @dataclass
 class Monkey:
@@ -16,21 +23,30 @@ class Monkey:
     inspections: int = 0
 
 
-monkeys = [
-    Monkey([79, 98], ('*', None, 19), 23, (2, 3)),
-    Monkey([54, 65, 75, 74], ('+', None, 6), 19, (2, 0)),
-    Monkey([79, 60, 97], ('*', None, None), 13, (1, 3)),
-    Monkey([74], ('+', None, 3), 17, (0, 1)),
-]
+def parse_monkey(block):
+    lines = block.strip().split('\n')
+    items = [int(i) for i in lines[1].split(':')[1].split(',')]
+    lhs, op, rhs = lines[2].split('=')[1].split()
+    operand = lambda o: None if o == 'old' else int(o)
+    test = int(lines[3].split()[-1])
+    target = (int(lines[4].split()[-1]), int(lines[5].split()[-1]))
+    return Monkey(items, (op, operand(lhs), operand(rhs)), test, target)
+
+
+# Read in the input (input.txt unless another is given)
+path = sys.argv[1] if len(sys.argv) > 1 else "input.txt"
+with open(path, "r") as f:
+    monkeys = [parse_monkey(block) for block in f.read().strip().split("\n\n")]
 
-for _ in tqdm.tqdm(range(1000)):
+# Worry levels only matter modulo the product of all the monkeys' tests
+modulus = math.prod(m.test for m in monkeys)
+
+for _ in tqdm(range(10000)):
     for m in monkeys:
         for i in m.items:
             new_item_value = (m.operation[1] or i) * (m.operation[2] or i) if m.operation[0] == '*' else (m.operation[1] or i) + (m.operation[2] or i)
             m.inspections += 1
-            if new_item_value > (23 * 19 * 17 * 17):
-                # If the new item value is greater than the product of all the monkeys' tests, then we need to reduce it
-                new_item_value = new_item_value % (23 * 19 * 13 * 17)
+            new_item_value = new_item_value % modulus
             if new_item_value % m.test == 0:
                 monkeys[m.target[0]].items.append(new_item_value)
             else:
@@ -41,3 +57,6 @@ sorted_by_inspections = sorted(monkeys, key=lambda m: m.inspections)
 # Print all of the monkeys and their inspections
 for (i, m) in enumerate(monkeys):
     print(i, m.inspections, m.items)
+
+# The monkey business goes on the last line
+print(sorted_by_inspections[-1].inspections * sorted_by_inspections[-2].inspections)
@@ -0,0 +1,228 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{debug, graph, trace, Generator, Graph, Grid, ParseError, Rng, Solution};
+use rand::Rng as _;
+
+pub struct Day12;
+
+#[derive(Debug)]
+pub struct Heightmap {
+    grid: Grid<char>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+// Height difference from one location to another
+fn climb(grid: &Grid<char>, from: (usize, usize), to: (usize, usize)) -> i32 {
+    (grid[to] as i32) - (grid[from] as i32)
+}
+
+// The squares one step from each square, where a step can climb at most one level
+fn climbable(grid: &Grid<char>) -> impl Graph<Node = (usize, usize)> + '_ {
+    graph::from_fn(move |square| {
+        grid.neighbors4(square)
+            .filter(move |&next| climb(grid, square, next) <= 1)
+            .map(|next| (next, 1))
+    })
+}
+
+// The steps of a path as a grid, numbered from the start, for drawing
+fn draw_path(grid: &Grid<char>, path: &[(usize, usize)]) -> Grid<i32> {
+    let mut steps = Grid::new(grid.height(), grid.width(), -1);
+    for (step, &square) in path.iter().enumerate() {
+        steps[square] = step as i32;
+    }
+    steps
+}
+
+// Write a heightmap `size` rows tall, at least six, and four times as wide. The
+// ground falls away from the summit one level at a time down to `a` at the start,
+// with some squares sunk a level below the ground around them.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let rows = size.max(6);
+    let columns = rows * 4;
+    let corners = [
+        (0, 0),
+        (0, columns - 1),
+        (rows - 1, 0),
+        (rows - 1, columns - 1),
+    ];
+    let distance = |a: (usize, usize), b: (usize, usize)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+
+    // Start in the corner farthest from the summit, which needs to be far enough
+    // away to climb all the way from `a` to `z`
+    let (end, start) = loop {
+        let end = (rng.random_range(0..rows), rng.random_range(0..columns));
+        let start = *corners
+            .iter()
+            .max_by_key(|&&corner| distance(corner, end))
+            .unwrap();
+        if distance(start, end) > 25 {
+            break (end, start);
+        }
+    };
+    let step = distance(start, end) / 26;
+
+    let mut output = String::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            let square = (row, column);
+            let mut height = 25 - (distance(square, end) / step).min(25);
+            if square == start {
+                output.push('S');
+                continue;
+            } else if square == end {
+                output.push('E');
+                continue;
+            } else if height > 0 && rng.random_bool(0.1) {
+                height -= 1;
+            }
+            output.push((b'a' + height as u8) as char);
+        }
+        output.push('\n');
+    }
+    output
+}
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Heightmap, ParseError> {
+        // Parse the input into a grid of heights, with the start and end marked
+        let mut grid = Grid::parse(input, "a height from `a` to `z`", |c| {
+            (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+        })?;
+        let start = grid
+            .position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::after(input, input, "a start marked `S`"))?;
+        let end = grid
+            .position(|&c| c == 'E')
+            .ok_or_else(|| ParseError::after(input, input, "an end marked `E`"))?;
+
+        // The start is at height `a` and the end at height `z`
+        grid[start] = 'a';
+        grid[end] = 'z';
+        Ok(Heightmap { grid, start, end })
+    }
+
+    fn part1(heightmap: &Heightmap, _: &()) -> impl Display {
+        let grid = &heightmap.grid;
+        let start = heightmap.start;
+        let end = heightmap.end;
+
+        debug!("\n{}", grid);
+        // Print the start and end
+        debug!("Start: ({}, {})", start.0, start.1);
+        debug!("End: ({}, {})", end.0, end.1);
+
+        // Head for the end, trying the squares closest to it first
+        let search = graph::astar(
+            &climbable(grid),
+            [start],
+            |square| square == end,
+            |square| (square.0.abs_diff(end.0) + square.1.abs_diff(end.1)) as u64,
+        );
+        let Some(path) = search.path(end) else {
+            return "no path".to_string();
+        };
+        debug!("\n{:>3}", draw_path(grid, &path));
+
+        // The number of steps
+        (path.len() - 1).to_string()
+    }
+
+    fn part2(heightmap: &Heightmap, _: &()) -> impl Display {
+        let grid = &heightmap.grid;
+        let end = heightmap.end;
+
+        debug!("\n{}", grid);
+        // Print the end
+        debug!("End: ({}, {})", end.0, end.1);
+
+        // Search from every square at height 'a' at once, so the first to reach
+        // the end is the closest
+        let starts = grid
+            .iter()
+            .filter(|&(_, &height)| height == 'a')
+            .map(|(square, _)| square);
+        let search = graph::bfs(&climbable(grid), starts, |square| square == end);
+        let Some(path) = search.path(end) else {
+            return "no path".to_string();
+        };
+        trace!("Path: {:?}", path);
+        debug!("Start: ({}, {})", path[0].0, path[0].1);
+        debug!("\n{:>3}", draw_path(grid, &path));
+
+        // The number of steps
+        (path.len() - 1).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Rows of heights with distinct start and end squares
+    type Drawn = (Vec<Vec<char>>, (usize, usize), (usize, usize));
+
+    fn heightmap() -> impl Strategy<Value = Drawn> {
+        (1..10usize, 2..10usize).prop_flat_map(|(rows, columns)| {
+            let grid = prop::collection::vec(
+                prop::collection::vec(prop::char::range('a', 'z'), columns),
+                rows,
+            );
+            let square = (0..rows, 0..columns);
+            (grid, square.clone(), square)
+                .prop_filter("start and end differ", |(_, start, end)| start != end)
+                .prop_map(|(mut grid, start, end)| {
+                    grid[start.0][start.1] = 'a';
+                    grid[end.0][end.1] = 'z';
+                    (grid, start, end)
+                })
+        })
+    }
+
+    // A wall too steep to climb leaves no way to the end, which is an answer of
+    // its own rather than a panic
+    #[test]
+    fn no_path() {
+        let heightmap = Day12::parse("Sbz\nabE\n").unwrap();
+        assert_eq!(Day12::part1(&heightmap, &()).to_string(), "no path");
+        assert_eq!(Day12::part2(&heightmap, &()).to_string(), "no path");
+    }
+
+    proptest! {
+        // The heights, start and end are parsed back as they were drawn
+        #[test]
+        fn round_trip((grid, start, end) in heightmap()) {
+            let mut rows = grid.clone();
+            rows[start.0][start.1] = 'S';
+            rows[end.0][end.1] = 'E';
+            let input = rows
+                .iter()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n");
+            let parsed = Day12::parse(&input).unwrap();
+            prop_assert_eq!(parsed.grid.rows().map(<[char]>::to_vec).collect::<Vec<_>>(), grid);
+            prop_assert_eq!(parsed.start, start);
+            prop_assert_eq!(parsed.end, end);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[a-zSE0 \né]{0,40}") {
+            let _ = Day12::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::{self, Display};
+
+use aoc_common::{debug, parse_field, trace, Generator, ParseError, Rng, Solution};
+use rand::Rng as _;
+
+pub struct Day13;
+
+pub struct Packet {
+    values: Vec<Packet>,
+    value: Option<usize>,
+}
+
+impl Packet {
+    // Parse a packet from `packet`, a slice of the whole `input`
+    fn new(input: &str, packet: &str) -> Result<Packet, ParseError> {
+        // Check to see if the input is a list of values
+        if let Some(rest) = packet.strip_prefix('[') {
+            // Remove the brackets, and parse the internal string
+            let inner = rest
+                .strip_suffix(']')
+                .ok_or_else(|| ParseError::after(input, packet, "`]`"))?;
+            let mut values: Vec<Packet> = Vec::new();
+
+            // Split the string with nested brackets into chunks
+            let mut chunks = Vec::new();
+            let mut chunk_start = 0;
+            let mut depth = 0;
+            for (i, c) in inner.char_indices() {
+                if c == '[' {
+                    depth += 1;
+                } else if c == ']' {
+                    depth -= 1;
+                }
+
+                if depth == 0 && c == ',' {
+                    chunks.push(&inner[chunk_start..i]);
+                    chunk_start = i + 1;
+                }
+            }
+            if chunk_start < inner.len() {
+                chunks.push(&inner[chunk_start..]);
+            }
+            // Construct the packets from the chunks
+            for chunk in chunks {
+                values.push(Packet::new(input, chunk)?);
+            }
+
+            return Ok(Packet {
+                values,
+                value: None,
+            });
+        }
+        Ok(Packet {
+            values: Vec::new(),
+            value: Some(parse_field(input, packet.trim(), "an integer or a list")?),
+        })
+    }
+
+    fn new_from_value(value: usize) -> Packet {
+        Packet {
+            values: Vec::new(),
+            value: Some(value),
+        }
+    }
+
+    fn new_list_from_value(value: usize) -> Packet {
+        Packet {
+            values: vec![Packet::new_from_value(value)],
+            value: None,
+        }
+    }
+}
+
+// Print a packet in the same form as the input
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(value) = self.value {
+            write!(f, "{}", value)
+        } else {
+            write!(f, "[")?;
+            for (i, value) in self.values.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{}", value)?;
+            }
+            write!(f, "]")
+        }
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Packet) -> bool {
+        match (self.value, other.value) {
+            // If both values are integers, check if they are equal
+            (Some(value1), Some(value2)) => value1 == value2,
+            // If both values are lists, check if each element is equal
+            (None, None) => {
+                self.values.len() == other.values.len()
+                    && self
+                        .values
+                        .iter()
+                        .zip(other.values.iter())
+                        .all(|(value1, value2)| value1 == value2)
+            }
+            // If one value is a list and the other is an integer, return false
+            _ => false,
+        }
+    }
+}
+
+fn in_order(packet1: &Packet, packet2: &Packet) -> i32 {
+    // Print
+    trace!("Comparing {} => {}", packet1, packet2);
+
+    match (packet1.value, packet2.value) {
+        // If both values are integers, lower should be first
+        (Some(value1), Some(value2)) => {
+            trace!("Integer:: Comparing {} and {}", value1, value2);
+            if value1 < value2 {
+                trace!("List in order!");
+                return 1;
+            } else if value1 > value2 {
+                trace!("List not in order!");
+                return -1;
+            }
+            0
+        }
+        // If both values are lists, then check to see if each element is in order
+        (None, None) => {
+            for (i, value1) in packet1.values.iter().enumerate() {
+                if i >= packet2.values.len() {
+                    trace!(
+                        "List:: {} is longer than {}",
+                        packet1.values.len(),
+                        packet2.values.len()
+                    );
+                    return -1;
+                }
+                let order = in_order(value1, &packet2.values[i]);
+                if order != 0 {
+                    return order;
+                }
+            }
+            // If the first list is shorter than the second, then the lists are in order
+            if packet1.values.len() < packet2.values.len() {
+                trace!(
+                    "List:: {} is shorter than {}",
+                    packet1.values.len(),
+                    packet2.values.len()
+                );
+                return 1;
+            }
+            0
+        }
+        // If one value is a list and the other is an integer, then convert the integer to a list
+        (None, Some(value2)) => {
+            trace!("1:: Converting integer to list: {}", value2);
+            let p2 = Packet::new_list_from_value(value2);
+            in_order(packet1, &p2)
+        }
+        (Some(value1), None) => {
+            trace!("2:: Converting integer to list: {}", value1);
+            let p1 = Packet::new_list_from_value(value1);
+            in_order(&p1, packet2)
+        }
+    }
+}
+
+// A random packet, nested at most `depth` lists deep
+fn random_packet(rng: &mut Rng, depth: usize) -> String {
+    let values = (0..rng.random_range(0..=5))
+        .map(|_| {
+            if depth > 0 && rng.random_bool(0.3) {
+                random_packet(rng, depth - 1)
+            } else {
+                rng.random_range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<String>>();
+    format!("[{}]", values.join(","))
+}
+
+// Write `size` pairs of packets
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n{}\n", random_packet(rng, 4), random_packet(rng, 4)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Packet>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<Packet>, ParseError> {
+        // Add a packet for each line
+        let packets = input
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|line| Packet::new(input, line.trim()))
+            .collect::<Result<Vec<Packet>, ParseError>>()?;
+
+        // Packets come in pairs
+        if packets.len() % 2 != 0 {
+            return Err(ParseError::after(
+                input,
+                input,
+                "the second packet of the last pair",
+            ));
+        }
+        Ok(packets)
+    }
+
+    fn part1(packets: &Vec<Packet>, _: &()) -> impl Display {
+        // For every pair of packets, check if it's in the right order
+        let mut in_order_value = 0;
+        for (i, pair) in packets.chunks(2).enumerate() {
+            if in_order(&pair[0], &pair[1]) > 0 {
+                debug!("{} is in order", i + 1);
+                in_order_value += i + 1;
+            } else {
+                debug!("{} is not in order", i + 1);
+            }
+        }
+        in_order_value
+    }
+
+    fn part2(packets: &Vec<Packet>, _: &()) -> impl Display {
+        // Add the two marker packets
+        let mut packets: Vec<&Packet> = packets.iter().collect();
+        let start_marker = Packet {
+            values: vec![Packet::new_list_from_value(2)],
+            value: None,
+        };
+        let end_marker = Packet {
+            values: vec![Packet::new_list_from_value(6)],
+            value: None,
+        };
+        packets.push(&start_marker);
+        packets.push(&end_marker);
+
+        // Sort the packets
+        packets.sort_by(|a, b| in_order(b, a).cmp(&0));
+
+        // Print the packets
+        for packet in &packets {
+            debug!("{}", packet);
+        }
+
+        // Get the indices of the two marker packets
+        let start_packet = packets.iter().position(|p| **p == start_marker).unwrap();
+        let end_packet = packets.iter().position(|p| **p == end_marker).unwrap();
+
+        // Print the indices
+        debug!("Start packet: {}", start_packet);
+        debug!("End packet: {}", end_packet);
+
+        // The product of the indices
+        (start_packet + 1) * (end_packet + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // The shape of a packet, to compare with what was parsed
+    #[derive(Clone, Debug, PartialEq)]
+    enum Tree {
+        Value(usize),
+        List(Vec<Tree>),
+    }
+
+    fn tree() -> impl Strategy<Value = Tree> {
+        let leaf = (0..20usize).prop_map(Tree::Value);
+        let tree = leaf.prop_recursive(4, 32, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(Tree::List)
+        });
+        // A packet is always a list at the top
+        prop::collection::vec(tree, 0..5).prop_map(Tree::List)
+    }
+
+    // Write a packet as it appears in the puzzle
+    fn render(tree: &Tree) -> String {
+        match tree {
+            Tree::Value(value) => value.to_string(),
+            Tree::List(values) => format!(
+                "[{}]",
+                values.iter().map(render).collect::<Vec<String>>().join(",")
+            ),
+        }
+    }
+
+    // The shape of a parsed packet
+    fn shape(packet: &Packet) -> Tree {
+        match packet.value {
+            Some(value) => Tree::Value(value),
+            None => Tree::List(packet.values.iter().map(shape).collect()),
+        }
+    }
+
+    proptest! {
+        // Every packet is parsed back into the shape it was written from
+        #[test]
+        fn round_trip(pairs in prop::collection::vec((tree(), tree()), 1..10)) {
+            let input = pairs
+                .iter()
+                .map(|(left, right)| format!("{}\n{}\n", render(left), render(right)))
+                .collect::<Vec<String>>()
+                .join("\n");
+            let parsed = Day13::parse(&input).unwrap();
+            let expected = pairs
+                .into_iter()
+                .flat_map(|(left, right)| [left, right])
+                .collect::<Vec<Tree>>();
+            prop_assert_eq!(parsed.iter().map(shape).collect::<Vec<Tree>>(), expected);
+        }
+
+        // Malformed input, such as unbalanced brackets, is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[\\[\\],0-9 \né]{0,40}") {
+            let _ = Day13::parse(&input);
+        }
+    }
+}
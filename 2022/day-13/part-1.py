@@ -3,6 +3,8 @@
 # This software is released under the MIT License.
 # https://opensource.org/licenses/MIT
 
+import sys
+
 
 def pair_in_order(p0, p1):
     if isinstance(p0, int) and isinstance(p1, int):
@@ -35,9 +37,10 @@ def pair_in_order(p0, p1):
     return None
 
 
-# Read in the input
-with open("input.txt", "r") as f:
-    lines = f.read().split("\n\n")
+# Read in the input (input.txt unless another is given)
+path = sys.argv[1] if len(sys.argv) > 1 else "input.txt"
+with open(path, "r") as f:
+    lines = f.read().strip().split("\n\n")
 
 # Parse the input into pairs
 pairs = []
@@ -0,0 +1,255 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{debug, parse_field, trace, Generator, ParseError, Rng, Solution, SparseGrid};
+use rand::Rng as _;
+
+pub struct Day14;
+
+// Where the sand pours in
+const SAND_SOURCE: (i64, i64) = (500, 0);
+
+// Draw the rock paths onto an empty cave
+fn draw_paths(paths: &[Vec<(i64, i64)>]) -> SparseGrid<char> {
+    let mut cave = SparseGrid::new();
+    for path in paths {
+        // For each pair of points, draw a "#" on the grid from the source to the destination
+        for (&(x1, y1), &(x2, y2)) in path.iter().zip(path.iter().skip(1)) {
+            trace!("({}, {}) -> ({}, {})", x1, y1, x2, y2);
+            if x1 == x2 {
+                trace!("Vertical line");
+                // Vertical line
+                for y in y1.min(y2)..=y1.max(y2) {
+                    cave.insert((x1, y), '#');
+                }
+            } else {
+                trace!("Horizontal line");
+                // Horizontal line
+                for x in x1.min(x2)..=x1.max(x2) {
+                    cave.insert((x, y1), '#');
+                }
+            }
+        }
+    }
+    cave
+}
+
+// Let a unit of sand fall from the source until it comes to rest, returning where.
+// Nothing is below the lowest rock, so sand that gets past it falls forever unless
+// there is a floor two rows further down.
+fn drop_sand(cave: &SparseGrid<char>, lowest: i64, floor: bool) -> Option<(i64, i64)> {
+    let (mut x, mut y) = SAND_SOURCE;
+    loop {
+        if y > lowest {
+            return floor.then_some((x, y));
+        }
+        // Fall straight down if we can, otherwise down + left, otherwise down + right
+        match [x, x - 1, x + 1]
+            .into_iter()
+            .find(|&next| !cave.contains((next, y + 1)))
+        {
+            Some(next) => {
+                x = next;
+                y += 1;
+            }
+            // The sand is blocked on all sides, so it's at rest
+            None => return Some((x, y)),
+        }
+    }
+}
+
+// Write `size` paths of rock, each a few straight lines turning at right angles.
+// The rock stays near the sand's source, in the same area as the real input.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut point = (rng.random_range(450..=550), rng.random_range(10..=150));
+            let mut points = vec![point];
+            for turn in 0..rng.random_range(1..=5) {
+                let last = point;
+                while point == last {
+                    if turn % 2 == 0 {
+                        point.0 = rng.random_range(last.0.max(455) - 5..=last.0.min(545) + 5);
+                    } else {
+                        point.1 = rng.random_range(last.1.max(15) - 5..=last.1.min(145) + 5);
+                    }
+                }
+                points.push(point);
+            }
+            points
+                .iter()
+                .map(|(x, y)| format!("{},{}", x, y))
+                .collect::<Vec<String>>()
+                .join(" -> ")
+                + "\n"
+        })
+        .collect()
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<(i64, i64)>>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<Vec<(i64, i64)>>, ParseError> {
+        // Parse each line into a sequence of points, e.g. "498,4 -> 498,6 -> 496,6"
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let mut path: Vec<(i64, i64)> = Vec::new();
+                for point in line.split("->") {
+                    let (x, y) = point
+                        .split_once(',')
+                        .ok_or_else(|| ParseError::after(input, point, "`,`"))?;
+                    let next = (
+                        parse_field(input, x.trim(), "an x coordinate")?,
+                        parse_field(input, y.trim(), "a y coordinate")?,
+                    );
+                    // Rock only runs straight across or straight down
+                    let last = path.last().copied().unwrap_or(next);
+                    if last.0 != next.0 && last.1 != next.1 {
+                        return Err(ParseError::at(
+                            input,
+                            point.trim(),
+                            "a point in the same row or column as the one before",
+                        ));
+                    }
+                    // The sand needs somewhere to pour in from
+                    let (x, y) = SAND_SOURCE;
+                    if (last.0.min(next.0)..=last.0.max(next.0)).contains(&x)
+                        && (last.1.min(next.1)..=last.1.max(next.1)).contains(&y)
+                    {
+                        return Err(ParseError::at(
+                            input,
+                            point.trim(),
+                            "rock that leaves the sand's source at 500,0 open",
+                        ));
+                    }
+                    path.push(next);
+                }
+                Ok(path)
+            })
+            .collect()
+    }
+
+    fn part1(paths: &Vec<Vec<(i64, i64)>>, _: &()) -> impl Display {
+        let mut cave = draw_paths(paths);
+        let lowest = cave.bounds().map_or(0, |bounds| bounds.max.y);
+
+        // Generate sand until some sand falls off the bottom, or rock holds so
+        // much that the sand piles up to the source
+        let mut units_at_rest = 0;
+        while let Some(rest) = drop_sand(&cave, lowest, false) {
+            cave.insert(rest, 'o');
+            units_at_rest += 1;
+            if rest == SAND_SOURCE {
+                break;
+            }
+        }
+
+        // Show where the sand came to rest
+        debug!("\n{}", cave);
+
+        // The number of units at rest
+        units_at_rest
+    }
+
+    fn part2(paths: &Vec<Vec<(i64, i64)>>, _: &()) -> impl Display {
+        let mut cave = draw_paths(paths);
+        let lowest = cave.bounds().map_or(0, |bounds| bounds.max.y);
+
+        // Generate sand until the source is blocked
+        let mut units_at_rest = 0;
+        loop {
+            // With the floor, every unit of sand comes to rest
+            let rest = drop_sand(&cave, lowest, true).unwrap();
+            cave.insert(rest, 'o');
+            units_at_rest += 1;
+            if rest == SAND_SOURCE {
+                break;
+            }
+        }
+
+        // Show where the sand came to rest
+        debug!("\n{}", cave);
+
+        // The number of units at rest
+        units_at_rest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // A path of rock below the source, turning between across and down at each point
+    fn path() -> impl Strategy<Value = Vec<(i64, i64)>> {
+        (
+            (0..1000i64, 1..200i64),
+            prop::collection::vec(0..1000i64, 0..5),
+        )
+            .prop_map(|(start, moves)| {
+                let mut point = start;
+                let mut path = vec![point];
+                for (turn, to) in moves.into_iter().enumerate() {
+                    if turn % 2 == 0 {
+                        point.0 = to;
+                    } else {
+                        point.1 = to % 199 + 1;
+                    }
+                    path.push(point);
+                }
+                path
+            })
+    }
+
+    // Rock that would need a diagonal line, or that covers the source, is
+    // rejected at the point that puts it there
+    #[test]
+    fn unsolvable_rock() {
+        let input = "498,4 -> 498,6 -> 496,8";
+        let expected = "a point in the same row or column as the one before";
+        assert_eq!(
+            Day14::parse(input),
+            Err(ParseError::at(input, &input[18..], expected))
+        );
+        let input = "490,0 -> 510,0";
+        let expected = "rock that leaves the sand's source at 500,0 open";
+        assert_eq!(
+            Day14::parse(input),
+            Err(ParseError::at(input, &input[9..], expected))
+        );
+    }
+
+    proptest! {
+        // Every path of rock is parsed back as it was written
+        #[test]
+        fn round_trip(paths in prop::collection::vec(path(), 1..20)) {
+            let input = paths
+                .iter()
+                .map(|path| {
+                    path.iter()
+                        .map(|(x, y)| format!("{},{}", x, y))
+                        .collect::<Vec<String>>()
+                        .join(" -> ")
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            prop_assert_eq!(Day14::parse(&input).unwrap(), paths);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "(->|[0-9, \né]){0,40}") {
+            let _ = Day14::parse(&input);
+        }
+    }
+}
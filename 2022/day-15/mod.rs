@@ -0,0 +1,287 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use aoc_common::{
+    cancelled, checkpoint, debug, info, params, parse_field, Bounds, Generator, IntervalSet,
+    ParseError, Point2, Rng, Solution, SparseGrid,
+};
+use rand::Rng as _;
+
+pub struct Day15;
+
+params! {
+    /// Where to look for the beacon.
+    pub struct Params {
+        /// The row part 1 counts the covered cells of
+        row: i64 = 2000000,
+        /// The largest x and y part 2 searches for the distress beacon
+        bound: i64 = 4000000,
+    }
+}
+
+// The furthest a sensor or beacon can be from the origin along either axis, so
+// the distances between them and the edges of their coverage fit in an i64
+const MAX_COORDINATE: i64 = 1_000_000_000_000;
+
+// Parse a coordinate, reporting it if it is further out than MAX_COORDINATE
+fn coordinate(input: &str, field: &str) -> Result<i64, ParseError> {
+    let expected = format!("an integer from -{} to {}", MAX_COORDINATE, MAX_COORDINATE);
+    match parse_field::<i64>(input, field, &expected)? {
+        value if value.abs() <= MAX_COORDINATE => Ok(value),
+        _ => Err(ParseError::at(input, field, expected)),
+    }
+}
+
+pub struct Scan {
+    // The sensors drawn as `S` and the beacons as `B`
+    map: SparseGrid<char>,
+    // Each sensor with the distance to its closest beacon
+    sensors: Vec<(Point2<i64>, i64)>,
+}
+
+// Write `size` sensors across the default search area. Every sensor's closest
+// beacon is nearer to it than a chosen distress beacon, which is never detected,
+// though other gaps in the coverage may come first in part 2's scan.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let bound = Params::default().bound;
+    let distress = Point2::new(rng.random_range(0..=bound), rng.random_range(0..=bound));
+    let mut output = String::new();
+    let mut sensors = 0;
+    while sensors < size {
+        let sensor = Point2::new(rng.random_range(0..=bound), rng.random_range(0..=bound));
+        let reach = sensor.manhattan(distress) - 1;
+        if reach < 1 {
+            continue;
+        }
+        let distance = rng.random_range(1..=reach.min(1000000));
+        let dx = rng.random_range(-distance..=distance);
+        let dy = (distance - dx.abs()) * if rng.random_bool(0.5) { 1 } else { -1 };
+        let beacon = sensor + Point2::new(dx, dy);
+        output += &format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            sensor.x, sensor.y, beacon.x, beacon.y
+        );
+        sensors += 1;
+    }
+    output
+}
+
+// The columns of `row` within (distance) of any sensor
+fn coverage(sensors: &[(Point2<i64>, i64)], row: i64) -> IntervalSet<i64> {
+    sensors
+        .iter()
+        .filter_map(|&(sensor, distance)| {
+            // Whatever distance isn't used reaching the row spreads out along it
+            let reach = distance - (sensor.y - row).abs();
+            (reach >= 0).then(|| sensor.x - reach..=sensor.x + reach)
+        })
+        .collect()
+}
+
+impl Solution for Day15 {
+    type Input = Scan;
+    type Params = Params;
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Scan, ParseError> {
+        // Parse the input to a map of beacons and sensors, and a list of sensors
+        let mut map = SparseGrid::new();
+        let mut sensors = Vec::new();
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            // Line contains:
+            // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+            let (sensor, beacon) = line
+                .split_once("closest beacon is at x=")
+                .ok_or_else(|| ParseError::after(input, line, "`closest beacon is at x=`"))?;
+
+            // Parse the beacon
+            let (bx, by) = beacon
+                .split_once(", y=")
+                .ok_or_else(|| ParseError::after(input, beacon, "`, y=`"))?;
+            let beacon = Point2::new(coordinate(input, bx)?, coordinate(input, by.trim())?);
+            map.insert(beacon, 'B');
+
+            // Parse the sensor
+            let sensor = sensor
+                .split_once("x=")
+                .ok_or_else(|| ParseError::at(input, sensor, "`Sensor at x=`"))?
+                .1
+                .split(':')
+                .next()
+                .unwrap();
+            let (sx, sy) = sensor
+                .split_once(", y=")
+                .ok_or_else(|| ParseError::after(input, sensor, "`, y=`"))?;
+            let sensor = Point2::new(coordinate(input, sx)?, coordinate(input, sy)?);
+            // A beacon stays on the map even if another sensor is at the same place
+            if map.get(sensor) != Some(&'B') {
+                map.insert(sensor, 'S');
+            }
+
+            // Get the distance (manhattan distance) from the sensor to the beacon
+            let distance = sensor.manhattan(beacon);
+
+            // Add the sensor to the list of sensors
+            sensors.push((sensor, distance));
+        }
+
+        Ok(Scan { map, sensors })
+    }
+
+    fn part1(scan: &Scan, params: &Params) -> impl Display {
+        let row = params.row;
+
+        // The cells on the row within (distance) of a sensor, except the beacons
+        let beacons = scan
+            .map
+            .iter()
+            .filter(|&(point, &mark)| point.y == row && mark == 'B')
+            .map(|(point, _)| point.x..=point.x)
+            .collect();
+        let cells = coverage(&scan.sensors, row).difference(&beacons);
+
+        // Draw the row with its sensors and beacons when it is narrow enough to read
+        if let (Some(first), Some(last)) = (cells.ranges().next(), cells.ranges().next_back()) {
+            let bounds = Bounds::point((*first.start(), row)).expand((*last.end(), row));
+            if bounds.width() <= 100 {
+                let mut drawn = cells
+                    .ranges()
+                    .flatten()
+                    .map(|x| ((x, row), '#'))
+                    .collect::<SparseGrid<char>>();
+                drawn.extend(
+                    scan.map
+                        .crop(bounds)
+                        .iter()
+                        .map(|(point, &mark)| (point, mark)),
+                );
+                debug!("\n{}", drawn);
+            }
+        }
+
+        // The number of cells
+        cells.total_len()
+    }
+
+    fn part2(scan: &Scan, params: &Params) -> impl Display {
+        let max_x = params.bound;
+        let max_y = params.bound;
+
+        // Look for a gap in the coverage of each row
+        // Rows before the one in the checkpoint, if any, are already known to be covered
+        let mut possible_beacon_locations = Vec::new();
+        let first_row = checkpoint::resume::<i64>().unwrap_or(0);
+        for row in first_row..=max_y {
+            // Stop if the runner has given up waiting; there is no answer to give yet
+            if cancelled() {
+                checkpoint::save(&row);
+                break;
+            }
+            if row % 10000 == 0 && checkpoint::due() {
+                checkpoint::save(&row);
+            }
+            let start = Instant::now();
+
+            // Any column in the search area that no sensor reaches could hold the beacon
+            if let Some(gap) = coverage(&scan.sensors, row).gaps(0..=max_x).ranges().next() {
+                possible_beacon_locations.push((*gap.start(), row));
+                break;
+            }
+
+            // Print progress
+            if row % 10000 == 0 {
+                let elapsed = start.elapsed();
+                info!(
+                    "Row {} done ({:0.4}%, ETA: {:?})",
+                    row,
+                    row as f32 * 100.0 / max_y as f32,
+                    Duration::from_secs_f32(elapsed.as_secs_f32() * (max_y - row) as f32)
+                );
+            }
+        }
+
+        // Print the possible beacon locations
+        for (x, y) in &possible_beacon_locations {
+            info!("Possible beacon location: {}, {}", x, y);
+        }
+
+        // Draw the search area with the distress beacon when it is small enough to read
+        let area = Bounds::point(Point2::ORIGIN).expand((max_x, max_y));
+        if area.width() <= 100 {
+            let mut drawn = scan.map.crop(area);
+            drawn.extend(possible_beacon_locations.iter().map(|&point| (point, '#')));
+            debug!("\n{}", drawn);
+        }
+
+        // The tuning frequency of the first possible location
+        match possible_beacon_locations.first() {
+            Some(&(x, y)) => (x * 4000000 + y).to_string(),
+            None => "no beacon found".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Coordinates too far out to measure between are rejected at the first one read
+    #[test]
+    fn extreme_coordinates() {
+        let input = "Sensor at x=-9000000000000000000, y=0: closest beacon is at x=9000000000000000000, y=0";
+        let expected = ParseError::at(
+            input,
+            &input[62..81],
+            "an integer from -1000000000000 to 1000000000000",
+        );
+        assert_eq!(Day15::parse(input).err(), Some(expected));
+    }
+
+    proptest! {
+        // Every sensor and beacon is parsed back as it was written
+        #[test]
+        fn round_trip(readings in prop::collection::vec((-1000000..1000000i64, -1000000..1000000i64, -1000000..1000000i64, -1000000..1000000i64), 1..30)) {
+            let input = readings
+                .iter()
+                .map(|(sx, sy, bx, by)| {
+                    format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}", sx, sy, bx, by)
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            let scan = Day15::parse(&input).unwrap();
+            let sensors = readings
+                .iter()
+                .map(|&(sx, sy, bx, by)| (Point2::new(sx, sy), (bx - sx).abs() + (by - sy).abs()))
+                .collect::<Vec<(Point2<i64>, i64)>>();
+            let beacons = readings
+                .iter()
+                .map(|&(_, _, bx, by)| Point2::new(bx, by))
+                .collect::<HashSet<Point2<i64>>>();
+            let parsed_beacons = scan
+                .map
+                .iter()
+                .filter(|&(_, &mark)| mark == 'B')
+                .map(|(point, _)| point)
+                .collect::<HashSet<Point2<i64>>>();
+            prop_assert_eq!(scan.sensors, sensors);
+            prop_assert_eq!(parsed_beacons, beacons);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "(Sensor at |closest beacon is at |x=|y=|-?[0-9]{1,20}|[0-9,: \né-]){0,20}") {
+            let _ = Day15::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,454 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{
+    cancelled, checkpoint, debug, graph, info, params, parse_field, Generator, ParseError, Rng,
+    Solution,
+};
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng as _;
+
+pub struct Day16;
+
+params! {
+    /// Where the search starts and how long it has.
+    pub struct Params {
+        /// The valve you start at
+        start: String = "AA".to_string(),
+        /// Minutes before the volcano erupts in part 1
+        minutes: usize = 30,
+        /// Minutes left in part 2 after teaching the elephant
+        minutes_with_elephant: usize = 26,
+    }
+}
+
+// A place the search has got to: (time remaining, open valves, pressure released
+// by the end, location). Bit `i` of the open valves is the `i`th flowing valve.
+type Step = (usize, u64, i32, usize);
+
+// Where a search has got to: the most pressure released with each set of open
+// valves, the steps still to explore, deepest last, and how many have been
+// explored. Depth first keeps the steps to a few per valve, so checkpoints stay small.
+type Search = (HashMap<u64, i32>, Vec<Step>, usize);
+
+pub struct Valves {
+    // Each valve is (name, flow rate, names of the connected valves)
+    valves: Vec<(String, i32, Vec<String>)>,
+    // Map from valve names to indices into valves
+    valve_map: HashMap<String, usize>,
+}
+
+// The index of the valve the search starts from, which `check` makes sure exists
+fn start_valve(input: &Valves, params: &Params) -> usize {
+    input.valve_map[&params.start]
+}
+
+// Get the pairwise distances between all the valves, plus one for the minute
+// it takes to open the valve at the end. Valves with no way between them are
+// too far apart for any amount of time.
+fn pairwise_distances(input: &Valves) -> Vec<Vec<usize>> {
+    let valves = &input.valves;
+    let mut tunnels = graph::AdjacencyList::new(valves.len());
+    for (i, (_, _, leads)) in valves.iter().enumerate() {
+        for valve in leads {
+            tunnels.add_edge(i, input.valve_map[valve], 1);
+        }
+    }
+    let distances = graph::floyd_warshall(&tunnels, valves.len())
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|distance| distance.map_or(usize::MAX, |distance| distance as usize + 1))
+                .collect::<Vec<usize>>()
+        })
+        .collect::<Vec<Vec<usize>>>();
+
+    // Print out the distances
+    for row in &distances {
+        debug!(
+            "{}",
+            row.iter().map(|d| format!("{:3} ", d)).collect::<String>()
+        );
+    }
+
+    distances
+}
+
+// The valves with a flow, the only ones worth going to
+fn flowing_valves(input: &Valves) -> Vec<usize> {
+    (0..input.valves.len())
+        .filter(|&i| input.valves[i].1 > 0)
+        .collect()
+}
+
+// A search with `minutes` left at `start`, or the one in the checkpoint if there is one
+fn start_search(start: usize, minutes: usize) -> Search {
+    checkpoint::resume().unwrap_or_else(|| (HashMap::new(), vec![(minutes, 0, 0, start)], 0))
+}
+
+// Explore every order of opening the flowing valves in the time there is, keeping
+// the most pressure released with each set of open valves. Returns false if the
+// run was cancelled first, saving the search to continue later.
+fn explore(
+    input: &Valves,
+    distances: &[Vec<usize>],
+    flowing: &[usize],
+    search: &mut Search,
+) -> bool {
+    let (best, steps, explored) = search;
+    while let Some(step) = steps.pop() {
+        if cancelled() {
+            steps.push(step);
+            checkpoint::save(&(&*best, &*steps, *explored));
+            return false;
+        }
+
+        // Stopping here is always an option
+        let (time, open, released, location) = step;
+        let most = best.entry(open).or_insert(released);
+        *most = (*most).max(released);
+
+        // Otherwise move to a closed valve there is time to reach and open it
+        for (bit, &valve) in flowing.iter().enumerate() {
+            let distance = distances[location][valve];
+            if open & 1 << bit != 0 || distance >= time {
+                continue;
+            }
+            let time = time - distance;
+            steps.push((
+                time,
+                open | 1 << bit,
+                released + input.valves[valve].1 * time as i32,
+                valve,
+            ));
+        }
+
+        // Print the most pressure released so far
+        if *explored % 100000 == 0 {
+            info!(
+                "Explored: {}, Max: {}, QL: {}",
+                explored,
+                best.values().max().unwrap(),
+                steps.len()
+            );
+        }
+        *explored += 1;
+        if *explored % 100000 == 0 && checkpoint::due() {
+            checkpoint::save(&(&*best, &*steps, *explored));
+        }
+    }
+    true
+}
+
+// Write a scan of `size` valves, at most every two-letter name, connected by
+// tunnels with up to five from each valve. As in the puzzle, at most fifteen
+// valves have a flow, and `AA` isn't one of them.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names = ('A'..='Z')
+        .flat_map(|a| ('A'..='Z').map(move |b| format!("{}{}", a, b)))
+        .filter(|name| name != "AA")
+        .collect::<Vec<String>>();
+    names.shuffle(rng);
+    names.truncate(count - 1);
+    names.insert(0, "AA".to_string());
+
+    let mut rates = vec![0; count];
+    let mut flowing = (1..count).collect::<Vec<usize>>();
+    flowing.shuffle(rng);
+    for &valve in flowing.iter().take(15) {
+        rates[valve] = rng.random_range(3..=25);
+    }
+
+    // Join every valve to an earlier one so they're all connected, then add some
+    // more tunnels between valves with room for them
+    let mut tunnels = vec![Vec::new(); count];
+    let join = |a: usize, b: usize, tunnels: &mut Vec<Vec<usize>>| {
+        tunnels[a].push(b);
+        tunnels[b].push(a);
+    };
+    for valve in 1..count {
+        let open = (0..valve)
+            .filter(|&other| tunnels[other].len() < 5)
+            .collect::<Vec<usize>>();
+        join(valve, *open.choose(rng).unwrap(), &mut tunnels);
+    }
+    for _ in 0..count / 2 {
+        let (a, b) = (rng.random_range(0..count), rng.random_range(0..count));
+        if a != b && !tunnels[a].contains(&b) && tunnels[a].len() < 5 && tunnels[b].len() < 5 {
+            join(a, b, &mut tunnels);
+        }
+    }
+
+    let mut order = (0..count).collect::<Vec<usize>>();
+    order.shuffle(rng);
+    order
+        .into_iter()
+        .map(|valve| {
+            let leads = tunnels[valve]
+                .iter()
+                .map(|&other| names[other].as_str())
+                .collect::<Vec<&str>>();
+            let (tunnel, valves) = if leads.len() == 1 {
+                ("tunnel leads", "valve")
+            } else {
+                ("tunnels lead", "valves")
+            };
+            format!(
+                "Valve {} has flow rate={}; {} to {} {}\n",
+                names[valve],
+                rates[valve],
+                tunnel,
+                valves,
+                leads.join(", ")
+            )
+        })
+        .collect()
+}
+
+impl Solution for Day16 {
+    type Input = Valves;
+    type Params = Params;
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Valves, ParseError> {
+        // Create a vector of valves, a map from valves to valve_vector_indices
+        // Each valve has a branching factor of at most 5
+        let mut valves: Vec<(String, i32, Vec<String>)> = Vec::new();
+        let mut valve_map = HashMap::new();
+        let mut tunnels = Vec::new();
+        let mut flowing = 0;
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            // Input is of the form "Valve XX has flow rate=YY; tunnels lead to valves AA, BB, CC, etc"
+            let (name_part, rest) = line
+                .split_once("has flow rate=")
+                .ok_or_else(|| ParseError::after(input, line, "`has flow rate=`"))?;
+            let valve_name = name_part
+                .split(' ')
+                .nth(1)
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| ParseError::at(input, name_part, "`Valve` and a valve name"))?;
+            let (flow_rate, valve_tunnels) = rest
+                .split_once(';')
+                .ok_or_else(|| ParseError::after(input, rest, "`;`"))?;
+            let valve_flow_rate = parse_field::<i32>(input, flow_rate, "a flow rate")?;
+            // The search keeps the open valves as the bits of a u64
+            if valve_flow_rate > 0 {
+                flowing += 1;
+                if flowing > 64 {
+                    return Err(ParseError::at(
+                        input,
+                        flow_rate,
+                        "a flow rate of 0, as at most 64 valves can have a flow",
+                    ));
+                }
+            }
+            let valve_tunnels = valve_tunnels
+                .split("valves")
+                .nth(1)
+                .or_else(|| valve_tunnels.split("valve").nth(1))
+                .ok_or_else(|| ParseError::at(input, valve_tunnels, "`valve` or `valves`"))?
+                .split(',')
+                .map(|valve| valve.trim())
+                .collect::<Vec<&str>>();
+
+            // Create the valve
+            valves.push((
+                valve_name.to_string(),
+                valve_flow_rate,
+                valve_tunnels
+                    .iter()
+                    .map(|valve| valve.to_string())
+                    .collect(),
+            ));
+            valve_map.insert(valve_name.to_string(), valves.len() - 1);
+            tunnels.extend(valve_tunnels);
+        }
+
+        // Tunnels can only lead to valves in the scan
+        for valve in tunnels {
+            if !valve_map.contains_key(valve) {
+                return Err(ParseError::at(
+                    input,
+                    valve,
+                    "the name of a valve in the scan",
+                ));
+            }
+        }
+
+        Ok(Valves { valves, valve_map })
+    }
+
+    fn part1(input: &Valves, params: &Params) -> impl Display {
+        let distances = pairwise_distances(input);
+        let flowing = flowing_valves(input);
+
+        // Determine the most pressure that can be released by wandering around the graph,
+        // continuing from a checkpoint if there is one. If the run is cancelled this is
+        // the best found so far.
+        let start = start_valve(input, params);
+        let mut search = start_search(start, params.minutes);
+        explore(input, &distances, &flowing, &mut search);
+
+        let (best, steps, explored) = &search;
+        let max_released = best.values().copied().max().unwrap_or(0);
+        info!(
+            "Explored: {}, Max: {}, QL: {}",
+            explored,
+            max_released,
+            steps.len()
+        );
+
+        max_released
+    }
+
+    fn part2(input: &Valves, params: &Params) -> impl Display {
+        let distances = pairwise_distances(input);
+        let flowing = flowing_valves(input);
+
+        // Find the most pressure released with each set of valves in the time left,
+        // continuing from a checkpoint if there is one. If the run is cancelled the
+        // pair below is the best found so far.
+        let start = start_valve(input, params);
+        let mut search = start_search(start, params.minutes_with_elephant);
+        explore(input, &distances, &flowing, &mut search);
+        let (best, steps, explored) = &search;
+
+        // We and the elephant each open a different set of valves. Trying the sets
+        // that release the most first, stop once no pair can beat the best found.
+        let mut sets = best
+            .iter()
+            .map(|(&open, &released)| (open, released))
+            .collect::<Vec<(u64, i32)>>();
+        sets.sort_unstable_by_key(|&(_, released)| Reverse(released));
+        let mut max_released = 0;
+        let mut best_pair = (0, 0);
+        for (i, &(ours, released)) in sets.iter().enumerate() {
+            if released * 2 <= max_released {
+                break;
+            }
+            for &(elephants, also_released) in &sets[i..] {
+                if released + also_released <= max_released {
+                    break;
+                }
+                if ours & elephants == 0 {
+                    max_released = released + also_released;
+                    best_pair = (ours, elephants);
+                }
+            }
+        }
+        info!(
+            "Explored: {}, Max: {}, QL: {}",
+            explored,
+            max_released,
+            steps.len()
+        );
+
+        // Print the valves each of us opens in the best solution
+        let names = |open: u64| {
+            flowing
+                .iter()
+                .enumerate()
+                .filter(|&(bit, _)| open & 1 << bit != 0)
+                .map(|(_, &valve)| input.valves[valve].0.as_str())
+                .collect::<Vec<&str>>()
+        };
+        info!("Our valves: {:?}", names(best_pair.0));
+        info!("Elephant's valves: {:?}", names(best_pair.1));
+
+        max_released
+    }
+
+    fn check(input: &Valves, params: &Params) -> Result<(), String> {
+        if !input.valve_map.contains_key(&params.start) {
+            return Err(format!(
+                "there is no valve named {} to start from",
+                params.start
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solve, Error, Overrides};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Starting from a valve that isn't in the scan is rejected rather than solved
+    #[test]
+    fn unknown_start() {
+        let overrides = Overrides::from([("start".to_string(), "ZZ".to_string())]);
+        assert_eq!(
+            solve::<Day16>(include_str!("test_input.txt"), 1, &overrides),
+            Err(Error::Params(
+                "there is no valve named ZZ to start from".to_string()
+            ))
+        );
+    }
+
+    // Valves with distinct names, each with tunnels to one or more of the others
+    fn valves() -> impl Strategy<Value = Vec<(String, i32, Vec<String>)>> {
+        prop::collection::btree_set("[A-Z]{2}", 1..10).prop_flat_map(|names| {
+            let names = names.into_iter().collect::<Vec<String>>();
+            let valve = (
+                0..30i32,
+                prop::collection::vec(prop::sample::select(names.clone()), 1..5),
+            );
+            prop::collection::vec(valve, names.len()).prop_map(move |valves| {
+                names
+                    .iter()
+                    .zip(valves)
+                    .map(|(name, (rate, tunnels))| (name.clone(), rate, tunnels))
+                    .collect()
+            })
+        })
+    }
+
+    proptest! {
+        // Every valve is parsed back as it was written, and can be found by name
+        #[test]
+        fn round_trip(valves in valves()) {
+            let input = valves
+                .iter()
+                .map(|(name, rate, tunnels)| {
+                    let (tunnel, valve) = if tunnels.len() == 1 {
+                        ("tunnel leads", "valve")
+                    } else {
+                        ("tunnels lead", "valves")
+                    };
+                    format!(
+                        "Valve {} has flow rate={}; {} to {} {}",
+                        name,
+                        rate,
+                        tunnel,
+                        valve,
+                        tunnels.join(", ")
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            let parsed = Day16::parse(&input).unwrap();
+            for (index, (name, _, _)) in valves.iter().enumerate() {
+                prop_assert_eq!(parsed.valve_map.get(name), Some(&index));
+            }
+            prop_assert_eq!(parsed.valves, valves);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "(Valve |has flow rate=|tunnels lead to valves |AA|BB|[0-9;, \né]){0,20}") {
+            let _ = Day16::parse(&input);
+        }
+    }
+}
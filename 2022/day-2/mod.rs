@@ -0,0 +1,139 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{Generator, ParseError, Rng, Solution};
+use rand::seq::IndexedRandom;
+
+pub struct Day02;
+
+fn get_score(round: &str, letter_scores: [usize; 3], table: &HashMap<&str, usize>) -> usize {
+    // Player A can pick from A, B, C (Rock, Paper Scissors)
+    // The score is made up of a part that only depends on the last character
+    // (X, Y or Z), plus a part that needs to be looked up in the table
+    let letter_score = if round.ends_with('X') {
+        letter_scores[0]
+    } else if round.ends_with('Y') {
+        letter_scores[1]
+    } else {
+        letter_scores[2]
+    };
+
+    letter_score
+        + table
+            .get(round)
+            .unwrap_or_else(|| panic!("Could not find round in possible outcomes: {}", round))
+}
+
+// Write `size` rounds of their shape and our response
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let theirs = ["A", "B", "C"].choose(rng).unwrap();
+            let ours = ["X", "Y", "Z"].choose(rng).unwrap();
+            format!("{} {}\n", theirs, ours)
+        })
+        .collect()
+}
+
+impl Solution for Day02 {
+    type Input = Vec<String>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        // Split the file based on lines, each of which is a round like "A Y"
+        input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (theirs, ours) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::after(input, line, "` `"))?;
+                if !matches!(theirs, "A" | "B" | "C") {
+                    return Err(ParseError::at(input, theirs, "`A`, `B` or `C`"));
+                }
+                if !matches!(ours, "X" | "Y" | "Z") {
+                    return Err(ParseError::at(input, ours, "`X`, `Y` or `Z`"));
+                }
+                Ok(line.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Vec<String>, _: &()) -> impl Display {
+        // Player B can pick from X, Y, Z (Rock, Paper Scissors)
+        // You get 1 point for playing rock, 2 points for playing paper, and 3 points for playing scissors
+        // You get 0 points for losing, 3 points for draw, and 6 points for winning
+        let possible_outcomes: HashMap<&str, usize> = HashMap::from([
+            ("A X", 3), // Rock ties with Rock
+            ("A Y", 6), // Rock loses to Paper
+            ("A Z", 0), // Rock beats Scissors
+            ("B X", 0), // Paper beats Rock
+            ("B Y", 3), // Paper ties with Paper
+            ("B Z", 6), // Paper loses to Scissors
+            ("C X", 6), // Scissors loses to Rock
+            ("C Y", 0), // Scissors beats Paper
+            ("C Z", 3), // Scissors ties with Scissors
+        ]);
+
+        // Sum the score of each round
+        rounds
+            .iter()
+            .map(|round| get_score(round, [1, 2, 3], &possible_outcomes))
+            .sum::<usize>()
+    }
+
+    fn part2(rounds: &Vec<String>, _: &()) -> impl Display {
+        // X, Y, Z are now the outcome (Lose, Draw, Win), so the last character
+        // gives the value score. The choice score is complicated, so we get
+        // it by looking at the table
+        let possible_outcomes: HashMap<&str, usize> = HashMap::from([
+            ("A X", 3), // Rock + Lose = Play Scissors = 3
+            ("A Y", 1), // Rock + Draw = Play Rock = 1
+            ("A Z", 2), // Rock + Win = Play Paper = 2
+            ("B X", 1), // Paper + Lose = Play Rock = 1
+            ("B Y", 2), // Paper + Draw = Play Paper = 2
+            ("B Z", 3), // Paper + Win = Play Scissors = 3
+            ("C X", 2), // Scissors + Lose = Play Paper = 2
+            ("C Y", 3), // Scissors + Draw = Play Scissors = 3
+            ("C Z", 1), // Scissors + Win = Play Rock = 1
+        ]);
+
+        // Sum the score of each round
+        rounds
+            .iter()
+            .map(|round| get_score(round, [0, 3, 6], &possible_outcomes))
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        // Every round is parsed back as it was written
+        #[test]
+        fn round_trip(rounds in prop::collection::vec(("[ABC]", "[XYZ]"), 1..50)) {
+            let rounds = rounds
+                .iter()
+                .map(|(theirs, ours)| format!("{} {}", theirs, ours))
+                .collect::<Vec<String>>();
+            prop_assert_eq!(Day02::parse(&rounds.join("\n")).unwrap(), rounds);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[ABCXYZ \né]{0,40}") {
+            let _ = Day02::parse(&input);
+        }
+    }
+}
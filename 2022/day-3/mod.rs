@@ -0,0 +1,181 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{Generator, ParseError, Rng, Solution};
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng as _;
+
+pub struct Day03;
+
+pub struct Rucksack {
+    contents: String,
+    items_lh: Vec<char>,
+    items_rh: Vec<char>,
+}
+
+impl Rucksack {
+    fn new(contents: &str) -> Rucksack {
+        // First half of the contents string is the left hand items
+        let items_lh: Vec<char> = contents.chars().take(contents.len() / 2).collect();
+
+        // Second half of the contents string is the right hand items
+        let items_rh: Vec<char> = contents.chars().skip(contents.len() / 2).collect();
+
+        Rucksack {
+            contents: contents.to_string(),
+            items_lh,
+            items_rh,
+        }
+    }
+
+    fn get_shared_priority(&self) -> usize {
+        // Find objects in left and right half
+        let mut shared_items: Vec<char> = Vec::new();
+        for item in &self.items_lh {
+            if self.items_rh.contains(item) && !shared_items.contains(item) {
+                shared_items.push(*item);
+            }
+        }
+
+        // Return the sum of the priorities
+        shared_items.into_iter().map(get_priority).sum()
+    }
+}
+
+fn get_priority(item: char) -> usize {
+    // Lower case letters get a priority of 1-26
+    // Upper case letters get a priority of 27-52
+    if item.is_lowercase() {
+        item as usize - 96
+    } else {
+        item as usize - 38
+    }
+}
+
+// Write `size` rucksacks, rounded up to whole groups of three. Each rucksack has
+// exactly one item in both compartments, and each group exactly one badge.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+    let mut output = String::new();
+    for _ in 0..size.div_ceil(3) {
+        // Every other item is left out of one rucksack of the group, so only the
+        // badge is in all three
+        let badge = *items.choose(rng).unwrap();
+        let mut allowed = vec![Vec::new(); 3];
+        for &item in items.iter().filter(|&&item| item != badge) {
+            let left_out = rng.random_range(0..3);
+            for (i, allowed) in allowed.iter_mut().enumerate() {
+                if i != left_out {
+                    allowed.push(item);
+                }
+            }
+        }
+
+        for mut allowed in allowed {
+            // The compartments draw from disjoint items, apart from the shared one
+            allowed.shuffle(rng);
+            let shared = allowed.pop().unwrap();
+            let (left, right) = allowed.split_at(allowed.len() / 2);
+            let half = rng.random_range(3..=16);
+            let mut left_half = vec![shared, badge];
+            left_half.extend((2..half).map(|_| *left.choose(rng).unwrap()));
+            let mut right_half = vec![shared];
+            right_half.extend((1..half).map(|_| *right.choose(rng).unwrap()));
+            left_half.shuffle(rng);
+            right_half.shuffle(rng);
+            output.extend(left_half);
+            output.extend(right_half);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+impl Solution for Day03 {
+    type Input = Vec<Rucksack>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+        // Construct a rucksack for each line
+        input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                // Items are letters, split evenly between the two compartments
+                if let Some((i, _)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                    return Err(ParseError::at(input, &line[i..], "an item letter"));
+                }
+                if line.len() % 2 != 0 {
+                    return Err(ParseError::after(input, line, "an even number of items"));
+                }
+                Ok(Rucksack::new(line))
+            })
+            .collect()
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>, _: &()) -> impl Display {
+        // Sum the shared priority of each rucksack
+        rucksacks
+            .iter()
+            .map(|rucksack| rucksack.get_shared_priority())
+            .sum::<usize>()
+    }
+
+    fn part2(rucksacks: &Vec<Rucksack>, _: &()) -> impl Display {
+        let mut sum = 0;
+        let mut hash_set = HashSet::new();
+        for (idx, rucksack) in rucksacks.iter().enumerate() {
+            if idx % 3 == 0 {
+                // Add the first line to the set
+                for item in rucksack.contents.chars() {
+                    hash_set.insert(item);
+                }
+            } else {
+                // Remove the items that are not in the set
+                hash_set.retain(|&item| rucksack.contents.contains(item));
+            }
+            if idx % 3 == 2 {
+                // Get the priority of the last remaining character
+                // (There should only be one item)
+                sum += get_priority(*hash_set.iter().next().unwrap());
+                hash_set.clear();
+            }
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        // Each rucksack is split evenly into its two compartments
+        #[test]
+        fn round_trip(rucksacks in prop::collection::vec("([a-zA-Z]{2}){1,20}", 1..30)) {
+            let parsed = Day03::parse(&rucksacks.join("\n")).unwrap();
+            prop_assert_eq!(parsed.len(), rucksacks.len());
+            for (rucksack, contents) in parsed.iter().zip(&rucksacks) {
+                let (left, right) = contents.split_at(contents.len() / 2);
+                prop_assert_eq!(&rucksack.contents, contents);
+                prop_assert_eq!(rucksack.items_lh.iter().collect::<String>(), left);
+                prop_assert_eq!(rucksack.items_rh.iter().collect::<String>(), right);
+            }
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[a-zA-Z0-9 \né]{0,40}") {
+            let _ = Day03::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{parse_field, Generator, IntervalSet, ParseError, Rng, Solution};
+use rand::Rng as _;
+
+pub struct Day04;
+
+type Assignment = ((usize, usize), (usize, usize));
+
+// Parse a range of sections, e.g. "2-4"
+fn parse_range(input: &str, range: &str) -> Result<(usize, usize), ParseError> {
+    let (x, y) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::after(input, range, "`-`"))?;
+    Ok((
+        parse_field(input, x, "a section number")?,
+        parse_field(input, y, "a section number")?,
+    ))
+}
+
+// The sections of a range as a set, to compare with the other elf's
+fn sections(&(start, end): &(usize, usize)) -> IntervalSet<usize> {
+    IntervalSet::from(start..=end)
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Assignment, ParseError> {
+    // Parse the line into a pair of tuples
+    // Split on "," then on "-"
+    let (start, end) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::after(input, line, "`,`"))?;
+
+    Ok((parse_range(input, start)?, parse_range(input, end)?))
+}
+
+// Write `size` pairs of section assignments
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let start = rng.random_range(1..=99);
+        format!("{}-{}", start, rng.random_range(start..=99))
+    };
+    (0..size)
+        .map(|_| format!("{},{}\n", range(rng), range(rng)))
+        .collect()
+}
+
+impl Solution for Day04 {
+    type Input = Vec<Assignment>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<Assignment>, ParseError> {
+        input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| parse_line(input, line))
+            .collect()
+    }
+
+    fn part1(assignments: &Vec<Assignment>, _: &()) -> impl Display {
+        // Count the pairs where one interval is completely contained within the other
+        assignments
+            .iter()
+            .filter(|(x, y)| {
+                let (x, y) = (sections(x), sections(y));
+                x.is_superset(&y) || y.is_superset(&x)
+            })
+            .count()
+    }
+
+    fn part2(assignments: &Vec<Assignment>, _: &()) -> impl Display {
+        // Count the pairs where one interval overlaps with the other
+        assignments
+            .iter()
+            .filter(|(x, y)| sections(x).overlaps(&sections(y)))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        // Every pair of assignments is parsed back as it was written
+        #[test]
+        fn round_trip(pairs in prop::collection::vec(((0..100usize, 0..100usize), (0..100usize, 0..100usize)), 1..50)) {
+            let input = pairs
+                .iter()
+                .map(|((a, b), (c, d))| format!("{}-{},{}-{}", a, b, c, d))
+                .collect::<Vec<String>>()
+                .join("\n");
+            prop_assert_eq!(Day04::parse(&input).unwrap(), pairs);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[0-9,\n é-]{0,40}") {
+            let _ = Day04::parse(&input);
+        }
+    }
+}
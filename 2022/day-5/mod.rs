@@ -0,0 +1,298 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{parse_field, Generator, ParseError, Rng, Solution};
+use rand::Rng as _;
+
+pub struct Day05;
+
+// A move of (from, to, num) crates
+type Action = (usize, usize, usize);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StackSet {
+    stacks: Vec<Vec<char>>,
+}
+
+impl StackSet {
+    fn new() -> StackSet {
+        StackSet { stacks: Vec::new() }
+    }
+
+    // Move num items from stack from to stack to, one at a time
+    fn apply_action(&mut self, from: usize, to: usize, num: usize) {
+        for _ in 0..num {
+            // Pop the top item from the from stack and push it onto the to stack
+            let item = self.stacks[from].pop().unwrap();
+            self.stacks[to].push(item);
+        }
+    }
+
+    // Move num items from stack from to stack to, all at once
+    fn apply_action_multiple(&mut self, from: usize, to: usize, num: usize) {
+        let mut processing_stack = Vec::new();
+        for _ in 0..num {
+            // Pop the top item from the from stack and push it onto the to stack
+            let item = self.stacks[from].pop().unwrap();
+            processing_stack.push(item);
+        }
+
+        // Pop the items off the processing stack and push them onto the to stack
+        while let Some(item) = processing_stack.pop() {
+            self.stacks[to].push(item);
+        }
+    }
+
+    // The top of each stack, skipping any that are empty
+    fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect()
+    }
+}
+
+fn parse_stacks(input: &str) -> Result<(StackSet, Vec<Action>), ParseError> {
+    // Parse the layout from the actions
+    let mut segments = input.split("\n\n");
+    let layout_str = segments.next().unwrap();
+    let actions_str = segments
+        .next()
+        .ok_or_else(|| ParseError::after(input, layout_str, "a blank line before the moves"))?;
+
+    // Parse the stacks
+    let layout_lines = layout_str
+        .split('\n')
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>();
+
+    let mut stacks = StackSet::new();
+    for line in layout_lines.iter().rev().skip(1) {
+        // We know the that line is a graphic representation of the stack layout
+        let chars = line.char_indices().collect::<Vec<(usize, char)>>();
+        for (index, c) in chars.chunks(4).enumerate() {
+            // C is a chunk of 4 characters which is either "[X] " or "    ".
+            let chunk = &line[c[0].0..];
+            if c.len() < 3 {
+                return Err(ParseError::at(input, chunk, "`[X]` or blank space"));
+            }
+            if c[1].1 != ' ' {
+                if c[0].1 != '[' || c[2].1 != ']' {
+                    return Err(ParseError::at(input, chunk, "`[X]` or blank space"));
+                }
+                while stacks.stacks.len() <= index {
+                    // We need to add a new stack
+                    stacks.stacks.push(Vec::new());
+                }
+                // The middle character is not a space, so it's a box
+                stacks.stacks[index].push(c[1].1);
+            }
+        }
+    }
+
+    // Parse a 1-based stack number into an index into the stacks
+    let num_stacks = stacks.stacks.len();
+    let stack_index = |field: &str| match field.parse::<usize>() {
+        Ok(n) if (1..=num_stacks).contains(&n) => Ok(n - 1),
+        _ => Err(ParseError::at(
+            input,
+            field,
+            format!("a stack number from 1 to {}", num_stacks),
+        )),
+    };
+
+    // Parse the actions as tuples of (from, to, num), keeping track of the height
+    // of each stack so no move takes more crates than there are
+    let actions_lines = actions_str.split('\n').filter(|line| !line.is_empty());
+    let mut heights = stacks.stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+    let mut actions = Vec::new();
+    for line in actions_lines {
+        // Split the line by whitespace, e.g. "move 1 from 2 to 1"
+        let split = line.split_whitespace().collect::<Vec<&str>>();
+        if split.len() != 6 || split[0] != "move" || split[2] != "from" || split[4] != "to" {
+            return Err(ParseError::at(input, line, "`move N from A to B`"));
+        }
+        let from = stack_index(split[3])?;
+        let to = stack_index(split[5])?;
+        let num = parse_field::<usize>(input, split[1], "a number of crates")?;
+        if num > heights[from] {
+            let expected = format!("at most {} crates, as in stack {}", heights[from], from + 1);
+            return Err(ParseError::at(input, split[1], expected));
+        }
+        heights[from] -= num;
+        heights[to] += num;
+        actions.push((from, to, num));
+    }
+
+    Ok((stacks, actions))
+}
+
+// Write nine stacks of crates and `size` moves between them. Moves never empty a
+// stack, so every stack still has a crate on top at the end.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut heights = (0..9)
+        .map(|_| rng.random_range(1..=8))
+        .collect::<Vec<usize>>();
+    heights[rng.random_range(0..9)] += 1;
+
+    // Draw the stacks from the top row down, then number them
+    let tallest = *heights.iter().max().unwrap();
+    let mut output = String::new();
+    for row in (0..tallest).rev() {
+        let line = heights
+            .iter()
+            .map(|&height| {
+                if row < height {
+                    format!("[{}]", rng.random_range('A'..='Z'))
+                } else {
+                    "   ".to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        output += line.trim_end();
+        output.push('\n');
+    }
+    output += " 1   2   3   4   5   6   7   8   9\n\n";
+
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.random_range(0..9);
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.random_range(1..9)) % 9;
+        let count = rng.random_range(1..heights[from]);
+        heights[from] -= count;
+        heights[to] += count;
+        output += &format!("move {} from {} to {}\n", count, from + 1, to + 1);
+    }
+    output
+}
+
+impl Solution for Day05 {
+    type Input = (StackSet, Vec<Action>);
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_stacks(input)
+    }
+
+    fn part1((stacks, actions): &Self::Input, _: &()) -> impl Display {
+        // Apply the actions to the stacks
+        let mut stacks = stacks.clone();
+        for &(from, to, num) in actions {
+            stacks.apply_action(from, to, num);
+        }
+        stacks.tops()
+    }
+
+    fn part2((stacks, actions): &Self::Input, _: &()) -> impl Display {
+        // Apply the actions to the stacks, moving crates in groups
+        let mut stacks = stacks.clone();
+        for &(from, to, num) in actions {
+            stacks.apply_action_multiple(from, to, num);
+        }
+        stacks.tops()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Draw the stacks as in the puzzle, from the top row down, then number them
+    fn render(stacks: &[Vec<char>], actions: &[Action]) -> String {
+        let tallest = stacks.iter().map(Vec::len).max().unwrap();
+        let mut output = String::new();
+        for row in (0..tallest).rev() {
+            let line = stacks
+                .iter()
+                .map(|stack| match stack.get(row) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            output += line.trim_end();
+            output.push('\n');
+        }
+        let numbers = (1..=stacks.len())
+            .map(|n| format!(" {} ", n))
+            .collect::<Vec<String>>();
+        output += &numbers.join(" ");
+        output += "\n\n";
+        for (from, to, num) in actions {
+            output += &format!("move {} from {} to {}\n", num, from + 1, to + 1);
+        }
+        output
+    }
+
+    // Up to nine non-empty stacks of crates, and moves between them
+    fn puzzle() -> impl Strategy<Value = (Vec<Vec<char>>, Vec<Action>)> {
+        prop::collection::vec(
+            prop::collection::vec(prop::char::range('A', 'Z'), 1..8),
+            1..=9,
+        )
+        .prop_flat_map(|stacks| {
+            let count = stacks.len();
+            let actions = prop::collection::vec((0..count, 0..count, 1..20usize), 0..20);
+            (Just(stacks), actions)
+        })
+        .prop_map(|(stacks, actions)| {
+            let actions = playable(&stacks, actions);
+            (stacks, actions)
+        })
+    }
+
+    // Keep only the moves that can be made, taking no more crates than a stack has
+    fn playable(stacks: &[Vec<char>], actions: Vec<Action>) -> Vec<Action> {
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+        let mut playable = Vec::new();
+        for (from, to, num) in actions {
+            let num = num.min(heights[from]);
+            if num > 0 {
+                heights[from] -= num;
+                heights[to] += num;
+                playable.push((from, to, num));
+            }
+        }
+        playable
+    }
+
+    // A move taking more crates than the stack has is rejected at its count
+    #[test]
+    fn too_many_crates() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 3 from 2 to 1\n";
+        let expected = "at most 2 crates, as in stack 2";
+        assert_eq!(
+            Day05::parse(input),
+            Err(ParseError::at(input, &input[41..], expected))
+        );
+    }
+
+    proptest! {
+        // The stacks and moves are parsed back as they were drawn
+        #[test]
+        fn round_trip((stacks, actions) in puzzle()) {
+            let (parsed, parsed_actions) = Day05::parse(&render(&stacks, &actions)).unwrap();
+            prop_assert_eq!(parsed.stacks, stacks);
+            prop_assert_eq!(parsed_actions, actions);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[\\[\\]A-C1-3 \né]{0,30}\n\n(move|from|to|[0-9 ]){0,10}") {
+            let _ = Day05::parse(&input);
+        }
+    }
+}
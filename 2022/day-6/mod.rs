@@ -0,0 +1,90 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{Generator, ParseError, Rng, Solution};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+pub struct Day06;
+
+// Find the index just after the first window of `size` unique characters
+fn find_marker(contents: &str, size: usize) -> usize {
+    // Put the first size - 1 characters into a vector
+    let mut chars: Vec<char> = contents.chars().take(size - 1).collect();
+
+    // For each character after that, add the character to the vector, and
+    // if the vector is made up of unique characters, return the index, otherwise
+    // remove the first character and continue
+    let mut index = size;
+    for c in contents.chars().skip(size - 1) {
+        chars.push(c);
+        if chars.len() == chars.iter().collect::<HashSet<_>>().len() {
+            break;
+        }
+        chars.remove(0);
+        index += 1;
+    }
+    index
+}
+
+// Write a datastream of `size` characters, at least 14. The first two thirds only
+// use three letters, so both markers come after them.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let prefix = (size - 14) * 2 / 3;
+    let mut stream = (0..prefix)
+        .map(|_| rng.random_range('a'..='c'))
+        .collect::<String>();
+    let mut letters = ('a'..='z').collect::<Vec<char>>();
+    letters.shuffle(rng);
+    stream.extend(&letters[..14]);
+    stream.extend((prefix + 14..size).map(|_| rng.random_range('a'..='z')));
+    stream + "\n"
+}
+
+impl Solution for Day06 {
+    type Input = String;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(contents: &String, _: &()) -> impl Display {
+        // Start of packet marker
+        find_marker(contents, 4)
+    }
+
+    fn part2(contents: &String, _: &()) -> impl Display {
+        // Start of message marker
+        find_marker(contents, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        // The datastream is parsed back without its trailing newline
+        #[test]
+        fn round_trip(stream in "[a-z]{1,100}") {
+            prop_assert_eq!(Day06::parse(&format!("{}\n", stream)).unwrap(), stream);
+        }
+
+        // Any input parses without panicking
+        #[test]
+        fn never_panics(input in "\\PC{0,40}") {
+            let _ = Day06::parse(&input);
+        }
+    }
+}
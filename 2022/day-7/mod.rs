@@ -0,0 +1,446 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{debug, params, parse_field, Generator, ParseError, Rng, Solution, Variant};
+use rand::Rng as _;
+
+#[path = "part-1-tree.rs"]
+pub mod tree;
+
+pub struct Day07;
+
+params! {
+    /// The sizes that matter when freeing up space.
+    pub struct Params {
+        /// Directories smaller than this count towards part 1
+        small_dir_limit: usize = 100000,
+        /// Size of the whole disk
+        disk_size: usize = 70000000,
+        /// Free space the update needs
+        update_size: usize = 30000000,
+    }
+}
+
+// A single line of the terminal output
+#[derive(Clone, Debug, PartialEq)]
+pub enum Line {
+    Cd(String),
+    Ls,
+    Dir(String),
+    File(String, usize),
+}
+
+struct FileSystem {
+    name: String,
+    directories: Vec<usize>,
+    files: Vec<(String, usize)>,
+    parent: usize,
+}
+
+impl FileSystem {
+    pub fn new(name: String, parent: usize) -> FileSystem {
+        FileSystem {
+            name,
+            directories: Vec::new(),
+            files: Vec::new(),
+            parent,
+        }
+    }
+}
+
+fn get_directory_size(filesystems: &[FileSystem], index: usize) -> usize {
+    let mut size = 0;
+    for (_, file_size) in &filesystems[index].files {
+        size += file_size;
+    }
+    for directory in &filesystems[index].directories {
+        size += get_directory_size(filesystems, *directory);
+    }
+    size
+}
+
+// Replay the terminal output to build up the filesystem, with the root at index 0
+fn build_filesystems(lines: &[Line]) -> Vec<FileSystem> {
+    let mut filesystems = Vec::new();
+
+    // Add the root filesystem
+    filesystems.push(FileSystem::new("/".to_string(), 0));
+    let mut cwd = 0;
+
+    for line in lines {
+        match line {
+            Line::Cd(directory) => {
+                if directory == ".." {
+                    // Go up a directory
+                    cwd = filesystems[cwd].parent;
+                } else if directory == "/" {
+                    cwd = 0;
+                } else {
+                    // Find the directory in the current working directory, which
+                    // parse has checked `ls` listed
+                    let index = filesystems[cwd]
+                        .directories
+                        .iter()
+                        .position(|&x| filesystems[x].name.eq(directory))
+                        .unwrap();
+                    // Set the current working directory to the index of the directory
+                    cwd = filesystems[cwd].directories[index];
+                }
+            }
+            Line::Ls => {
+                // Ignore this - since it doesn't matter.
+            }
+            Line::Dir(name) => {
+                // Check to see if the directory already exists
+                if filesystems[cwd]
+                    .directories
+                    .iter()
+                    .any(|&x| filesystems[x].name.eq(name))
+                {
+                    continue;
+                }
+                // Create a new directory
+                filesystems.push(FileSystem::new(name.to_string(), cwd));
+                // Add the index of the directory to the current working directory
+                let index = filesystems.len() - 1;
+                filesystems[cwd].directories.push(index);
+            }
+            Line::File(name, size) => {
+                // Check to see if the file already exists
+                if filesystems[cwd].files.iter().any(|x| x.0.eq(name)) {
+                    continue;
+                }
+                // Add the file to the current working directory
+                filesystems[cwd].files.push((name.to_string(), *size));
+            }
+        }
+    }
+
+    filesystems
+}
+
+// A random lowercase name for a file or directory
+fn random_name(rng: &mut Rng) -> String {
+    let mut name = (0..rng.random_range(1..=8))
+        .map(|_| rng.random_range('a'..='z'))
+        .collect::<String>();
+    if rng.random_bool(0.3) {
+        name.push('.');
+        name.extend((0..3).map(|_| rng.random_range('a'..='z')));
+    }
+    name
+}
+
+// Write the transcript of exploring a tree of `size` directories. File sizes are
+// scaled to fill around 50000000 of the disk, so part 2 usually has to free space,
+// but never the whole disk.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let largest = (50000000 / size.max(1)).max(2000);
+    let mut directories = vec![FileSystem::new("/".to_string(), 0)];
+    let mut total = 0;
+    for index in 0..size.max(1) {
+        if index > 0 {
+            let parent = rng.random_range(0..index);
+            let name = loop {
+                let name = random_name(rng);
+                if !directories[parent]
+                    .directories
+                    .iter()
+                    .any(|&i| directories[i].name == name)
+                {
+                    break name;
+                }
+            };
+            directories.push(FileSystem::new(name, parent));
+            directories[parent].directories.push(index);
+        }
+        for _ in 0..rng.random_range(0..=4) {
+            let file_size = rng.random_range(1000..=largest);
+            let name = random_name(rng);
+            if total + file_size < 60000000
+                && !directories[index].files.iter().any(|(n, _)| *n == name)
+            {
+                total += file_size;
+                directories[index].files.push((name, file_size));
+            }
+        }
+    }
+
+    // Walk the tree depth first, listing each directory on the way in
+    let mut output = "$ cd /\n".to_string();
+    let mut stack = vec![(0, false)];
+    while let Some((index, listed)) = stack.pop() {
+        if listed {
+            if index != 0 {
+                output += "$ cd ..\n";
+            }
+            continue;
+        }
+        let directory = &directories[index];
+        if index != 0 {
+            output += &format!("$ cd {}\n", directory.name);
+        }
+        output += "$ ls\n";
+        for &child in &directory.directories {
+            output += &format!("dir {}\n", directories[child].name);
+        }
+        for (name, file_size) in &directory.files {
+            output += &format!("{} {}\n", file_size, name);
+        }
+        stack.push((index, true));
+        stack.extend(
+            directory
+                .directories
+                .iter()
+                .rev()
+                .map(|&child| (child, false)),
+        );
+    }
+    output
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Line>;
+    type Params = Params;
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const VARIANTS: &'static [Variant] = &[Variant {
+        name: "tree",
+        part: 1,
+        run: tree::run,
+    }];
+
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        let mut lines = Vec::new();
+        // The path to the current directory, and every directory listed so far,
+        // so `cd` can only go into a directory that `ls` has shown
+        let mut cwd = Vec::new();
+        let mut known = HashSet::new();
+        for line in input.split('\n').filter(|line| !line.is_empty()) {
+            // Command is "$ cd" or "$ ls" or a file descriptor
+            if line.starts_with('$') {
+                // Get the command
+                let command = line
+                    .split(' ')
+                    .nth(1)
+                    .ok_or_else(|| ParseError::after(input, line, "a command"))?;
+                match command {
+                    "cd" => {
+                        let dir = line
+                            .split(' ')
+                            .nth(2)
+                            .ok_or_else(|| ParseError::after(input, line, "a directory"))?;
+                        match dir {
+                            "/" => cwd.clear(),
+                            ".." => {
+                                cwd.pop();
+                            }
+                            _ => {
+                                cwd.push(dir);
+                                if !known.contains(&cwd) {
+                                    return Err(ParseError::at(
+                                        input,
+                                        dir,
+                                        "a directory listed by `ls`",
+                                    ));
+                                }
+                            }
+                        }
+                        lines.push(Line::Cd(dir.to_string()))
+                    }
+                    "ls" => lines.push(Line::Ls),
+                    _ => return Err(ParseError::at(input, command, "`cd` or `ls`")),
+                }
+            } else {
+                // File descriptor
+                let (size, name) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::after(input, line, "a name"))?;
+                if size.eq("dir") {
+                    let mut path = cwd.clone();
+                    path.push(name);
+                    known.insert(path);
+                    lines.push(Line::Dir(name.to_string()));
+                } else {
+                    let size = parse_field(input, size, "a file size or `dir`")?;
+                    lines.push(Line::File(name.to_string(), size));
+                }
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<Line>, params: &Params) -> impl Display {
+        let filesystems = build_filesystems(lines);
+
+        // Sum the sizes of all of the small directories
+        (0..filesystems.len())
+            .map(|i| get_directory_size(&filesystems, i))
+            .filter(|&size| size < params.small_dir_limit)
+            .sum::<usize>()
+    }
+
+    fn part2(lines: &Vec<Line>, params: &Params) -> impl Display {
+        let filesystems = build_filesystems(lines);
+
+        // Get a sorted list of the directories by size
+        let mut directories: Vec<usize> = (0..filesystems.len()).collect();
+        directories.sort_by(|a, b| {
+            get_directory_size(&filesystems, *b).cmp(&get_directory_size(&filesystems, *a))
+        });
+
+        // Print the directories
+        let free_space = params.disk_size - get_directory_size(&filesystems, 0);
+        debug!("Free space: {}", free_space);
+
+        // The smallest directory that frees up enough space is the last one
+        // that passes the check
+        let mut smallest = 0;
+        for directory in directories {
+            let size = get_directory_size(&filesystems, directory);
+            if free_space + size > params.update_size {
+                debug!("{}: {}", filesystems[directory].name, size);
+                smallest = size;
+            }
+        }
+        smallest
+    }
+
+    fn check(lines: &Vec<Line>, params: &Params) -> Result<(), String> {
+        // The files have to fit on the disk, and so does the update
+        let used = get_directory_size(&build_filesystems(lines), 0);
+        if params.disk_size < used {
+            return Err(format!(
+                "disk_size must be at least the {} already in use",
+                used
+            ));
+        }
+        if params.update_size > params.disk_size {
+            return Err(format!(
+                "update_size must be at most the disk_size of {}",
+                params.disk_size
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solve, Error, Overrides};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // A command or a line of `ls` output
+    fn line() -> impl Strategy<Value = Line> {
+        prop_oneof![
+            "[a-z./]{1,10}".prop_map(Line::Cd),
+            Just(Line::Ls),
+            "[a-z.]{1,10}".prop_map(Line::Dir),
+            ("[a-z.]{1,10}", 0..1000000usize).prop_map(|(name, size)| Line::File(name, size)),
+        ]
+    }
+
+    // List each directory just before changing into it, as `cd` needs
+    fn listed(lines: Vec<Line>) -> Vec<Line> {
+        let mut listed = Vec::new();
+        for line in lines {
+            if let Line::Cd(dir) = &line {
+                if dir != "/" && dir != ".." {
+                    listed.push(Line::Dir(dir.clone()));
+                }
+            }
+            listed.push(line);
+        }
+        listed
+    }
+
+    // A disk too small for its files, or for the update, is rejected rather than solved
+    #[test]
+    fn disk_too_small() {
+        let input = include_str!("test_input.txt");
+        let overrides = Overrides::from([("disk_size".to_string(), "10".to_string())]);
+        assert_eq!(
+            solve::<Day07>(input, 2, &overrides),
+            Err(Error::Params(
+                "disk_size must be at least the 48381165 already in use".to_string()
+            ))
+        );
+        let overrides = Overrides::from([("update_size".to_string(), "70000001".to_string())]);
+        assert_eq!(
+            solve::<Day07>(input, 2, &overrides),
+            Err(Error::Params(
+                "update_size must be at most the disk_size of 70000000".to_string()
+            ))
+        );
+    }
+
+    // A `cd` into a directory no `ls` has shown is rejected at its name
+    #[test]
+    fn unknown_directory() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd a\n$ cd b\n";
+        let expected = "a directory listed by `ls`";
+        assert_eq!(
+            Day07::parse(input),
+            Err(ParseError::at(input, &input[30..], expected))
+        );
+    }
+
+    // Write a line as it appears in the transcript
+    fn render(line: &Line) -> String {
+        match line {
+            Line::Cd(dir) => format!("$ cd {}", dir),
+            Line::Ls => "$ ls".to_string(),
+            Line::Dir(name) => format!("dir {}", name),
+            Line::File(name, size) => format!("{} {}", size, name),
+        }
+    }
+
+    // The tree variant reads its shell script, where `cd test` from inside
+    // `object` finds no such directory and the script carries on from there
+    #[test]
+    fn tree_script() {
+        let script = include_str!("test_tree_input.txt");
+        assert_eq!(tree::run(script, &Overrides::new()).unwrap(), "1280");
+    }
+
+    // Going up from the root stays there in the tree variant, as in the main solution
+    #[test]
+    fn tree_up_from_root() {
+        let transcript = "$ cd /\n$ cd ..\n$ ls\n100 a\n";
+        assert_eq!(tree::run(transcript, &Overrides::new()).unwrap(), "100");
+        assert_eq!(
+            solve::<Day07>(transcript, 1, &Overrides::new()).unwrap(),
+            "100"
+        );
+    }
+
+    // The tree variant agrees with the main solution on a transcript
+    #[test]
+    fn tree_transcript() {
+        let transcript = include_str!("test_input.txt");
+        assert_eq!(tree::run(transcript, &Overrides::new()).unwrap(), "95437");
+    }
+
+    proptest! {
+        // Every line of the transcript is parsed back as it was written
+        #[test]
+        fn round_trip(lines in prop::collection::vec(line(), 1..50).prop_map(listed)) {
+            let input = lines.iter().map(render).collect::<Vec<String>>().join("\n");
+            prop_assert_eq!(Day07::parse(&input).unwrap(), lines);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "([$ ]|cd|ls|dir|[a-z0-9./\né]){0,40}") {
+            let _ = Day07::parse(&input);
+        }
+    }
+}
@@ -4,10 +4,12 @@
 // https://opensource.org/licenses/MIT
 
 use std::cell::RefCell;
-use std::env;
-use std::fs;
 use std::rc::Rc;
 
+use aoc_common::{debug, parse_field, warn, Error, Overrides, Params as _, ParseError, Solution};
+
+use super::{Day07, Line, Params};
+
 struct Node {
     name: String,
     children: Vec<Rc<RefCell<Node>>>,
@@ -37,7 +39,7 @@ impl Node {
                 return Some(child.clone());
             }
         }
-        return None;
+        None
     }
 
     // Compute the size of the node
@@ -63,74 +65,120 @@ fn add_directory(cwd: &Rc<RefCell<Node>>, name: &str) -> Rc<RefCell<Node>> {
 // Traverse the tree and pretty print it with tabs
 fn traverse(node: &Rc<RefCell<Node>>, depth: usize) {
     let dir = node.borrow();
-    for _ in 0..depth {
-        print!("\t");
-    }
-    println!("d {} {}", dir.name, dir.size());
+    debug!("{}d {} {}", "\t".repeat(depth), dir.name, dir.size());
     for child in &dir.children {
         traverse(child, depth + 1);
     }
     for (filename, size) in &dir.files {
-        for _ in 0..depth + 1 {
-            print!("\t");
+        debug!("{}f {} {}", "\t".repeat(depth + 1), filename, size);
+    }
+}
+
+// Sum the sizes of every directory below the node that is smaller than the limit
+fn small_directory_sizes(node: &Rc<RefCell<Node>>, limit: usize) -> usize {
+    let dir = node.borrow();
+    let size = dir.size();
+    let mut total = if size < limit { size } else { 0 };
+    for child in &dir.children {
+        total += small_directory_sizes(child, limit);
+    }
+    total
+}
+
+/// Parse a script of shell commands that builds a filesystem, one per line:
+/// `mkdir <name>`, `touch <name> <size>`, `cd <name>` and `ls`, as in
+/// test_tree_input.txt. A terminal transcript from the puzzle, with its `$`
+/// prompts, is read the same way as the main solution reads it.
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    if input.lines().any(|line| line.starts_with('$')) {
+        return Day07::parse(input);
+    }
+
+    let mut lines = Vec::new();
+    for line in input.lines() {
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            Some(command) => command,
+            None => continue,
+        };
+        let mut argument = |expected: &str| {
+            words
+                .next()
+                .ok_or_else(|| ParseError::after(input, line, expected))
+        };
+        match command {
+            "mkdir" => lines.push(Line::Dir(argument("a directory name")?.to_string())),
+            "touch" => {
+                let name = argument("a file name")?;
+                let size = parse_field(input, argument("a file size")?, "a file size")?;
+                lines.push(Line::File(name.to_string(), size));
+            }
+            "cd" => lines.push(Line::Cd(argument("a directory name")?.to_string())),
+            "ls" => lines.push(Line::Ls),
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    command,
+                    "`mkdir`, `touch`, `cd` or `ls`",
+                ))
+            }
         }
-        println!("f {} {}", filename, size);
     }
+    Ok(lines)
 }
 
-fn main() {
-    // Read the input file from the command line
-    let filename = env::args().nth(1).expect("No filename given");
-    let contents = fs::read_to_string(filename).expect("Could not read file");
+/// Run the tree variant on either kind of input.
+pub fn run(input: &str, overrides: &Overrides) -> Result<String, Error> {
+    let params = Params::with_overrides(overrides).map_err(Error::Params)?;
+    let lines = parse(input)?;
+    Ok(part1(&lines, &params).to_string())
+}
 
+/// Part 1, building the filesystem as a tree of reference counted nodes.
+pub fn part1(lines: &[Line], params: &Params) -> usize {
     // Create a new tree
     let root = Rc::new(RefCell::new(Node::new("/".to_string(), None)));
     let mut cwd = root.clone();
 
-    // Parse the input files with commands
-    for line in contents.lines() {
-        let command = line.split_whitespace().next().unwrap();
-        match command {
-            "ls" => {
+    // Replay the terminal output
+    for line in lines {
+        match line {
+            Line::Ls => {
                 let dir = cwd.borrow();
                 for child in &dir.children {
-                    println!("d {} {}", child.borrow().name, child.borrow().size());
+                    debug!("d {} {}", child.borrow().name, child.borrow().size());
                 }
                 for (filename, size) in &dir.files {
-                    println!("f {} {}", filename, size);
+                    debug!("f {} {}", filename, size);
                 }
             }
-            "mkdir" => {
-                let dir_name = line.split_whitespace().nth(1).unwrap();
-                add_directory(&cwd, dir_name);
+            Line::Dir(dir_name) => {
+                if cwd.borrow().get_directory(dir_name).is_none() {
+                    add_directory(&cwd, dir_name);
+                }
             }
-            "touch" => {
-                let filename = line.split_whitespace().nth(1).unwrap();
-                let size = line
-                    .split_whitespace()
-                    .nth(2)
-                    .unwrap()
-                    .parse::<usize>()
-                    .unwrap();
-                cwd.borrow_mut().add_file(filename, size);
+            Line::File(filename, size) => {
+                if !cwd.borrow().files.iter().any(|(name, _)| name == filename) {
+                    cwd.borrow_mut().add_file(filename, *size);
+                }
             }
-            "cd" => {
-                let dir_name = line.split_whitespace().nth(1).unwrap();
-                if dir_name.eq("..") {
-                    let parent = cwd.borrow().parent.clone().unwrap();
-                    cwd = parent;
-                } else if dir_name.eq("/") {
+            Line::Cd(dir_name) => {
+                if dir_name == ".." {
+                    // Going up from the root stays at the root, as in the main solution
+                    let parent = cwd.borrow().parent.clone();
+                    if let Some(parent) = parent {
+                        cwd = parent;
+                    }
+                } else if dir_name == "/" {
                     cwd = root.clone();
                 } else {
                     let target = cwd.borrow().get_directory(dir_name);
-                    if target.is_some() {
-                        cwd = target.unwrap();
-                    } else {
-                        println!("Directory not found: {}", dir_name);
+                    match target {
+                        Some(target) => cwd = target,
+                        None => warn!("Directory not found: {}", dir_name),
                     }
                 }
             }
-            _ => {}
         }
     }
 
@@ -138,5 +186,7 @@ fn main() {
     traverse(&root, 0);
 
     // Print the size of the tree
-    println!("Size of tree: {}", cwd.borrow().size());
+    debug!("Size of tree: {}", cwd.borrow().size());
+
+    small_directory_sizes(&root, params.small_dir_limit)
 }
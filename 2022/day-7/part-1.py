@@ -3,6 +3,9 @@
 # This software is released under the MIT License.
 # https://opensource.org/licenses/MIT
 
+import sys
+
+
 class Directory:
     def __init__(self, name, parent=None):
         self.name = name
@@ -25,8 +28,9 @@ def get_small_dirs(root):
 
 def main():
 
-    # Load the input file
-    with open("input.txt", "r") as f:
+    # Load the input file (input.txt unless another is given)
+    path = sys.argv[1] if len(sys.argv) > 1 else "input.txt"
+    with open(path, "r") as f:
         lines = f.readlines()
 
     # Create the root directory
@@ -72,8 +76,9 @@ def main():
     for small_dir in small_dirs:
         print(small_dir.name)
 
-    # Get the sum of the sizes of the small dirs
-    print('Sum of small dirs', sum([small_dir.size() for small_dir in small_dirs]))
+    # Get the sum of the sizes of the small dirs (the answer goes on the last line)
+    print('Sum of small dirs')
+    print(sum([small_dir.size() for small_dir in small_dirs]))
 
 
 if __name__ == "__main__":
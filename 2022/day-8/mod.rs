@@ -0,0 +1,114 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::grid::ORTHOGONAL;
+use aoc_common::{trace, Generator, Grid, ParseError, Rng, Solution};
+use rand::Rng as _;
+
+pub struct Day08;
+
+// Write a `size` by `size` grid of tree heights
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| char::from_digit(rng.random_range(0..10), 10).unwrap())
+                .chain(['\n'])
+                .collect::<String>()
+        })
+        .collect()
+}
+
+// Number of trees that can be seen from `position` looking in the direction of
+// `step`, up to and including the first one at least as tall
+fn visible_trees(grid: &Grid<u32>, position: (usize, usize), step: (isize, isize)) -> usize {
+    let height = grid[position];
+    let mut distance = 0;
+    for &tree in grid.ray(position, step) {
+        distance += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    distance
+}
+
+impl Solution for Day08 {
+    type Input = Grid<u32>;
+    type Params = ();
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+        // Each character is the height of a tree
+        Grid::from_digits(input)
+    }
+
+    fn part1(grid: &Grid<u32>, _: &()) -> impl Display {
+        // A tree is visible if every tree between it and the edge in some direction
+        // is shorter. Trees on the edge have nothing in the way.
+        grid.iter()
+            .filter(|&(position, &height)| {
+                ORTHOGONAL
+                    .iter()
+                    .any(|&step| grid.ray(position, step).all(|&tree| tree < height))
+            })
+            .count()
+    }
+
+    fn part2(grid: &Grid<u32>, _: &()) -> impl Display {
+        let mut viewing_distance = 0;
+        for (position, _) in grid.iter() {
+            // Find the distance to the first tree at least as tall, looking up, left,
+            // right and down
+            let [vu, vl, vr, vd] = ORTHOGONAL.map(|step| visible_trees(grid, position, step));
+
+            // Print the distance to the closest tree in each direction
+            trace!(
+                "({},{}) -- {} {} {} {} -- {}",
+                position.0,
+                position.1,
+                vu,
+                vl,
+                vr,
+                vd,
+                vl * vr * vu * vd
+            );
+
+            // Set the viewing distance to the maximum of the current viewing distance and the current tree's viewing distance
+            viewing_distance = viewing_distance.max(vl * vr * vu * vd);
+        }
+        viewing_distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        // Every tree height is parsed back as it was written
+        #[test]
+        fn round_trip(grid in prop::collection::vec(prop::collection::vec(0..10u32, 10), 1..10)) {
+            let input = grid
+                .iter()
+                .map(|row| row.iter().map(|height| height.to_string()).collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n");
+            let parsed = Day08::parse(&input).unwrap();
+            prop_assert_eq!(parsed.rows().map(<[u32]>::to_vec).collect::<Vec<_>>(), grid);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[0-9a \né]{0,40}") {
+            let _ = Day08::parse(&input);
+        }
+    }
+}
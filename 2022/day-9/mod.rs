@@ -0,0 +1,160 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{
+    params, parse_field, trace, Direction, Generator, ParseError, Point2, Rng, Solution, SparseGrid,
+};
+use rand::seq::IndexedRandom;
+use rand::Rng as _;
+
+pub struct Day09;
+
+params! {
+    /// The shape of the rope.
+    pub struct Params {
+        /// Knots in the rope for part 2, including the head and tail
+        knots: usize = 10,
+    }
+}
+
+// Draw the rope over the cells the tail has visited, with the head as `H`, the
+// other knots numbered and the start as `s`
+fn draw_rope(knots: &[Point2<i64>], visited: &SparseGrid<char>) -> SparseGrid<char> {
+    let mut drawn = visited.clone();
+    drawn.insert((0, 0), 's');
+    // Knots nearer the head are drawn over the ones behind them
+    for (i, &knot) in knots.iter().enumerate().rev() {
+        let mark = match i {
+            0 => 'H',
+            i => char::from_digit(i as u32 % 10, 10).unwrap(),
+        };
+        drawn.insert(knot, mark);
+    }
+    drawn
+}
+
+// Pull the head of a rope with `knots` knots through the moves, returning the
+// number of cells the tail visits
+fn pull_rope(moves: &[(Direction, i32)], knots: usize) -> usize {
+    let mut tail_visited_cells = SparseGrid::new();
+    let mut knots = vec![Point2::<i64>::ORIGIN; knots];
+
+    tail_visited_cells.insert(knots[knots.len() - 1], '#');
+    for &(direction, distance) in moves {
+        // Move the head in the direction
+        for _ in 0..distance {
+            knots[0] += direction.step();
+            for i in 1..knots.len() {
+                // A knot that is no longer touching the one ahead of it moves one
+                // step toward it, diagonally if they don't share a row or column
+                if knots[i].chebyshev(knots[i - 1]) > 1 {
+                    knots[i] = knots[i].step_toward(knots[i - 1]);
+                }
+                // Assert that each knot ends up touching the one ahead of it
+                assert!(knots[i].chebyshev(knots[i - 1]) <= 1);
+            }
+            // Add the new tail to the visited cells
+            tail_visited_cells.insert(knots[knots.len() - 1], '#');
+        }
+        // Show the rope after each move
+        trace!(
+            "{} {}\n{}",
+            direction,
+            distance,
+            draw_rope(&knots, &tail_visited_cells)
+        );
+    }
+
+    // The number of cells the tail visited
+    tail_visited_cells.len()
+}
+
+// Write `size` moves of the head of the rope
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+            format!("{} {}\n", direction, rng.random_range(1..=19))
+        })
+        .collect()
+}
+
+impl Solution for Day09 {
+    type Input = Vec<(Direction, i32)>;
+    type Params = Params;
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Vec<(Direction, i32)>, ParseError> {
+        // Each line is a direction followed by a distance, e.g. "R 4"
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let direction = line.chars().next().unwrap();
+                let direction = Direction::try_from(direction)
+                    .map_err(|_| ParseError::at(input, line, "`U`, `D`, `L` or `R`"))?;
+                let distance = line[1..].trim();
+                Ok((direction, parse_field(input, distance, "a distance")?))
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Vec<(Direction, i32)>, _: &Params) -> impl Display {
+        // Just the head and the tail
+        pull_rope(moves, 2)
+    }
+
+    fn part2(moves: &Vec<(Direction, i32)>, params: &Params) -> impl Display {
+        pull_rope(moves, params.knots)
+    }
+
+    fn check(_: &Vec<(Direction, i32)>, params: &Params) -> Result<(), String> {
+        // A rope needs at least a head, which is also its tail
+        if params.knots == 0 {
+            return Err("knots must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solve, Error, Overrides};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // A rope with no knots is rejected rather than solved
+    #[test]
+    fn no_knots() {
+        let overrides = Overrides::from([("knots".to_string(), "0".to_string())]);
+        assert_eq!(
+            solve::<Day09>(include_str!("test_input.txt"), 2, &overrides),
+            Err(Error::Params("knots must be at least 1".to_string()))
+        );
+    }
+
+    proptest! {
+        // Every move is parsed back as it was written
+        #[test]
+        fn round_trip(moves in prop::collection::vec((prop::sample::select(Direction::ALL.to_vec()), 0..100i32), 1..50)) {
+            let input = moves
+                .iter()
+                .map(|(direction, distance)| format!("{} {}", direction, distance))
+                .collect::<Vec<String>>()
+                .join("\n");
+            prop_assert_eq!(Day09::parse(&input).unwrap(), moves);
+        }
+
+        // Malformed input is an error rather than a panic
+        #[test]
+        fn never_panics(input in "[UDLR0-9 \né-]{0,40}") {
+            let _ = Day09::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Solutions for Advent of Code 2022.
+//!
+//! Alternate implementations live as submodules of their day and are
+//! registered as that day's variants.
+
+use aoc_common::Day;
+
+#[path = "day-1/mod.rs"]
+pub mod day01;
+
+#[path = "day-2/mod.rs"]
+pub mod day02;
+
+#[path = "day-3/mod.rs"]
+pub mod day03;
+
+#[path = "day-4/mod.rs"]
+pub mod day04;
+
+#[path = "day-5/mod.rs"]
+pub mod day05;
+
+#[path = "day-6/mod.rs"]
+pub mod day06;
+
+#[path = "day-7/mod.rs"]
+pub mod day07;
+
+#[path = "day-8/mod.rs"]
+pub mod day08;
+
+#[path = "day-9/mod.rs"]
+pub mod day09;
+
+#[path = "day-10/mod.rs"]
+pub mod day10;
+
+#[path = "day-11/mod.rs"]
+pub mod day11;
+
+#[path = "day-12/mod.rs"]
+pub mod day12;
+
+#[path = "day-13/mod.rs"]
+pub mod day13;
+
+#[path = "day-14/mod.rs"]
+pub mod day14;
+
+#[path = "day-15/mod.rs"]
+pub mod day15;
+
+#[path = "day-16/mod.rs"]
+pub mod day16;
+
+/// Every solution in this crate, keyed by day.
+pub const SOLUTIONS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+    Day::new::<day13::Day13>(13),
+    Day::new::<day14::Day14>(14),
+    Day::new::<day15::Day15>(15),
+    Day::new::<day16::Day16>(16),
+];
@@ -0,0 +1,180 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Timing the parse and solve phases of each day, with a history kept in each
+//! day's `bench.json` so a run can be compared against the one before it.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::{Day, Overrides};
+use serde::{Deserialize, Serialize};
+
+use crate::{input, params};
+
+/// Name of the benchmark history inside a day's directory.
+pub const HISTORY: &str = "bench.json";
+
+/// Median timings of one benchmark run, in nanoseconds. Parts that were not
+/// benchmarked are left out.
+#[derive(Serialize, Deserialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch when the run finished.
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub input: String,
+    pub parse_ns: u64,
+    pub part1_ns: Option<u64>,
+    pub part2_ns: Option<u64>,
+}
+
+impl Record {
+    fn phases(&self) -> [(&'static str, Option<u64>); 3] {
+        [
+            ("parse", Some(self.parse_ns)),
+            ("part1", self.part1_ns),
+            ("part2", self.part2_ns),
+        ]
+    }
+}
+
+fn median(samples: &mut [Duration]) -> u64 {
+    samples.sort();
+    samples[samples.len() / 2].as_nanos() as u64
+}
+
+// Read the benchmark history for a day; a missing file is an empty history
+pub fn load_history(dir: &Path) -> Result<Vec<Record>, String> {
+    let path = dir.join(HISTORY);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+    serde_json::from_str(&contents)
+        .map_err(|err| format!("Could not parse {}: {}", path.display(), err))
+}
+
+fn save_history(dir: &Path, history: &[Record]) -> Result<(), String> {
+    let path = dir.join(HISTORY);
+    let contents = serde_json::to_string_pretty(history).unwrap();
+    fs::write(&path, contents + "\n")
+        .map_err(|err| format!("Could not write {}: {}", path.display(), err))
+}
+
+// Time each of the given parts `iterations` times. Every run parses the input
+// again, so the parse median is taken over the runs of all parts.
+pub fn bench_day(
+    solution: &Day,
+    input: &Path,
+    overrides: &Overrides,
+    parts: &[u32],
+    iterations: usize,
+) -> Result<Record, String> {
+    let contents = input::read_file(input)?;
+
+    let mut parse = Vec::new();
+    let mut solve = [None, None];
+    for &part in parts {
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let (_, times) = (solution.time)(&contents, part, overrides)
+                .map_err(|err| err.with_file(input).to_string())?;
+            parse.push(times.parse);
+            samples.push(times.solve);
+        }
+        solve[part as usize - 1] = Some(median(&mut samples));
+    }
+
+    Ok(Record {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        iterations,
+        input: input.file_name().unwrap().to_string_lossy().into_owned(),
+        parse_ns: median(&mut parse),
+        part1_ns: solve[0],
+        part2_ns: solve[1],
+    })
+}
+
+// Print a run alongside the previous one, returning whether any phase got
+// slower by more than both `threshold` (a fraction of the previous time) and
+// `min_delta`, so jitter in phases that take next to no time isn't flagged
+pub fn report(
+    label: &str,
+    record: &Record,
+    previous: Option<&Record>,
+    threshold: f64,
+    min_delta: Duration,
+) -> bool {
+    let mut regressed = false;
+    let mut line = format!("{:<16}", label);
+    for (i, (phase, ns)) in record.phases().into_iter().enumerate() {
+        let ns = match ns {
+            Some(ns) => ns,
+            None => continue,
+        };
+        line += &format!("  {} {:>10.2?}", phase, Duration::from_nanos(ns));
+
+        let before = previous.and_then(|previous| previous.phases()[i].1);
+        if let Some(before) = before.filter(|before| *before > 0) {
+            let change = ns as f64 / before as f64 - 1.0;
+            line += &format!(" ({:+.0}%)", change * 100.0);
+            if change > threshold && ns - before > min_delta.as_nanos() as u64 {
+                line += " REGRESSION";
+                regressed = true;
+            }
+        }
+    }
+    println!("{}", line);
+    regressed
+}
+
+/// Settings shared by every day in a benchmark run.
+pub struct Options {
+    pub iterations: usize,
+    /// Slowdown, as a fraction of the previous time, that counts as a regression.
+    pub threshold: f64,
+    /// Smallest slowdown that counts as a regression, however large a fraction
+    /// of the previous time it is.
+    pub min_delta: Duration,
+    /// Whether to append the run to the day's history.
+    pub save: bool,
+}
+
+// Benchmark a day and report it against the last recorded run on the same
+// input, returning whether it regressed
+pub fn bench_and_record(
+    label: &str,
+    solution: &Day,
+    dir: &Path,
+    input: &Path,
+    parts: &[u32],
+    options: &Options,
+) -> Result<bool, String> {
+    let mut history = load_history(dir)?;
+    let name = input.file_name().unwrap_or_default().to_string_lossy();
+    let overrides = params::load(dir, &name)?;
+    let record = bench_day(solution, input, &overrides, parts, options.iterations)?;
+    let previous = history
+        .iter()
+        .rev()
+        .find(|previous| previous.input == record.input);
+    let regressed = report(
+        label,
+        &record,
+        previous,
+        options.threshold,
+        options.min_delta,
+    );
+    if options.save {
+        history.push(record);
+        save_history(dir, &history)?;
+    }
+    Ok(regressed)
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Solving under a wall-clock budget. A solution that runs over is cancelled
+//! through its token, and gets a grace period to return its best answer so far
+//! before it is abandoned.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::{cancel, CancelToken};
+
+/// How long a cancelled solution has to stop before it is abandoned.
+pub const GRACE: Duration = Duration::from_secs(5);
+
+/// How a solve under a budget ended.
+pub enum Outcome<T> {
+    /// It finished within the budget.
+    Finished(T),
+    /// It was cancelled and stopped early, so the result is only the best so far.
+    Partial(T),
+    /// It panicked, with the panic message.
+    Panicked(String),
+    /// It was cancelled and didn't stop within the grace period.
+    Abandoned,
+}
+
+// The message a panic was raised with
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "the solution panicked".to_string()
+    }
+}
+
+// Solve on this thread with the token installed, catching a panic so it can be reported
+pub fn solve_with_token<T>(token: &CancelToken, solve: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| cancel::with_token(token, solve)))
+        .map_err(|payload| panic_message(payload.as_ref()))
+}
+
+// Solve on a thread of its own, cancelling it once the budget runs out. Without a
+// budget the solve runs to the end on this thread.
+pub fn solve_within<T: Send + 'static>(
+    budget: Option<Duration>,
+    solve: impl FnOnce() -> T + Send + 'static,
+) -> Outcome<T> {
+    let token = CancelToken::new();
+    let budget = match budget {
+        Some(budget) => budget,
+        None => {
+            return match solve_with_token(&token, solve) {
+                Ok(result) => Outcome::Finished(result),
+                Err(message) => Outcome::Panicked(message),
+            }
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let thread_token = token.clone();
+    thread::spawn(move || {
+        // The receiver is gone if the solve was abandoned
+        let _ = sender.send(solve_with_token(&thread_token, solve));
+    });
+
+    let partial = match receiver.recv_timeout(budget) {
+        Ok(result) => Ok(result),
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Err(receiver.recv_timeout(GRACE))
+        }
+        Err(RecvTimeoutError::Disconnected) => unreachable!("the solve thread always sends"),
+    };
+    match partial {
+        Ok(Ok(result)) => Outcome::Finished(result),
+        Err(Ok(Ok(result))) => Outcome::Partial(result),
+        Ok(Err(message)) | Err(Ok(Err(message))) => Outcome::Panicked(message),
+        Err(Err(_)) => Outcome::Abandoned,
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Running every implementation of a part on the same input side by side.
+
+use std::time::{Duration, Instant};
+
+use aoc_common::{Day, Error, Overrides};
+
+/// The name the main implementation of a part is listed under.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// The answer and timing of one implementation.
+pub struct Outcome {
+    pub name: &'static str,
+    pub answer: Result<String, Error>,
+    pub elapsed: Duration,
+}
+
+// Run the main implementation and every variant of a part
+pub fn compare(solution: &Day, part: u32, contents: &str, overrides: &Overrides) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    let start = Instant::now();
+    let answer = (solution.run)(contents, part, overrides);
+    outcomes.push(Outcome {
+        name: DEFAULT_VARIANT,
+        answer,
+        elapsed: start.elapsed(),
+    });
+
+    for variant in solution.variants(part) {
+        let start = Instant::now();
+        let answer = (variant.run)(contents, overrides);
+        outcomes.push(Outcome {
+            name: variant.name,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    outcomes
+}
+
+// Print each outcome against the main implementation's answer, returning
+// whether they all agree
+pub fn report(outcomes: &[Outcome]) -> bool {
+    let expected = outcomes[0].answer.as_ref().ok();
+    let width = outcomes
+        .iter()
+        .map(|outcome| outcome.name.len())
+        .max()
+        .unwrap();
+    let mut agree = true;
+    for outcome in outcomes {
+        let (status, answer) = match &outcome.answer {
+            Ok(answer) if Some(answer) == expected => ("agrees", answer.clone()),
+            Ok(answer) => ("DIFFERS", answer.clone()),
+            Err(Error::Parse(err)) => ("ERROR", format!("expected {}", err.expected)),
+            Err(Error::Params(err)) => ("ERROR", err.clone()),
+        };
+        if status != "agrees" {
+            agree = false;
+        }
+        // Multi-line answers are shown from the line after the table row
+        let answer = if answer.contains('\n') {
+            format!("\n{}", answer)
+        } else {
+            answer
+        };
+        println!(
+            "{:<width$}  {:>10.2?}  {:<7}  {}",
+            outcome.name,
+            outcome.elapsed,
+            status,
+            answer,
+            width = width
+        );
+    }
+    agree
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Checking solutions against the Python prototypes kept next to some days.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use aoc_common::Runner;
+
+use crate::params;
+use crate::verify::{print_diff, Summary};
+
+// The reference script for a part, e.g. `day-7/part-1.py`
+fn reference_script(dir: &Path, part: u32) -> Option<String> {
+    let script = format!("part-{}.py", part);
+    dir.join(&script).exists().then_some(script)
+}
+
+// Run a reference script on an input file, returning the last line it prints
+fn run_reference(python: &str, dir: &Path, script: &str, input: &Path) -> Result<String, String> {
+    let output = Command::new(python)
+        .arg(script)
+        .arg(input)
+        .current_dir(dir)
+        .output()
+        .map_err(|err| format!("Could not run {}: {}", python, err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} exited with {}\n{}",
+            script,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ));
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .map(|line| line.trim().to_string())
+        .ok_or_else(|| format!("{} printed nothing", script))
+}
+
+// Run each part that has a reference script and compare it with the solution's answer
+pub fn crosscheck_day(
+    year: u32,
+    day: u32,
+    dir: &Path,
+    input: &str,
+    python: &str,
+    run: Runner,
+) -> Result<Summary, String> {
+    let mut summary = Summary::default();
+    let scripts = (1..=2)
+        .filter_map(|part| Some((part, reference_script(dir, part)?)))
+        .collect::<Vec<_>>();
+    if scripts.is_empty() {
+        return Ok(summary);
+    }
+
+    let path = dir.join(input);
+    // The scripts run from the day's directory, so hand them an absolute path
+    let path = fs::canonicalize(&path)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+    let overrides = params::load(dir, input)?;
+
+    for (part, script) in scripts {
+        let label = format!(
+            "{} day {} part {} ({} vs {})",
+            year, day, part, input, script
+        );
+        let expected = match run_reference(python, dir, &script, &path) {
+            Ok(expected) => expected,
+            Err(err) => {
+                println!("FAIL {}", label);
+                for line in err.lines() {
+                    println!("    {}", line);
+                }
+                summary.failed += 1;
+                continue;
+            }
+        };
+        let actual = match run(&contents, part, &overrides) {
+            Ok(actual) => actual,
+            Err(err) => {
+                println!("FAIL {}", label);
+                for line in err.with_file(&path).to_string().lines() {
+                    println!("    {}", line);
+                }
+                summary.failed += 1;
+                continue;
+            }
+        };
+        if actual.trim() == expected {
+            println!("PASS {}", label);
+            summary.passed += 1;
+        } else {
+            println!("FAIL {}", label);
+            print_diff(&expected, actual.trim());
+            summary.failed += 1;
+        }
+    }
+
+    Ok(summary)
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Where the runner reads a day's input from: a file, possibly gzipped, or stdin.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+
+/// The input argument that reads from stdin.
+pub const STDIN: &str = "-";
+
+/// Every gzip stream starts with these two bytes.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+// Short names for the conventional inputs in a day's directory
+const ALIASES: &[(&str, &str)] = &[("input", "input.txt"), ("test", "test_input.txt")];
+
+/// Where an input comes from.
+#[derive(Clone)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Resolve an input argument for the day in `dir`. Without one, this is the
+    /// day's `input.txt`. Otherwise `-` is stdin, and any other name is tried as
+    /// given, then within the day's directory (where `input` and `test` stand for
+    /// `input.txt` and `test_input.txt`). Each file may also be gzipped with a `.gz`
+    /// suffix. A name that matches nothing is kept as given, so reading it reports
+    /// the path that was asked for.
+    pub fn resolve(dir: &Path, arg: Option<&Path>) -> Source {
+        let arg = match arg {
+            Some(arg) if arg == Path::new(STDIN) => return Source::Stdin,
+            Some(arg) => arg,
+            None => return Source::find(&[dir.join("input.txt")]),
+        };
+
+        let mut candidates = vec![arg.to_path_buf(), dir.join(arg)];
+        for (alias, file) in ALIASES {
+            if arg == Path::new(alias) {
+                candidates.push(dir.join(file));
+            }
+        }
+        Source::find(&candidates)
+    }
+
+    // The first candidate that exists, plain or gzipped, or else the first candidate
+    fn find(candidates: &[PathBuf]) -> Source {
+        let path = candidates
+            .iter()
+            .flat_map(|path| [path.clone(), gzipped(path)])
+            .find(|path| path.is_file())
+            .unwrap_or_else(|| candidates[0].clone());
+        Source::File(path)
+    }
+
+    /// Read the whole input, decompressing it if it is gzipped.
+    pub fn read(&self) -> Result<String, String> {
+        let mut bytes = Vec::new();
+        match self {
+            Source::Stdin => io::stdin().read_to_end(&mut bytes),
+            Source::File(path) => {
+                fs::File::open(path).and_then(|mut file| file.read_to_end(&mut bytes))
+            }
+        }
+        .map_err(|err| format!("Could not read {}: {}", self, err))?;
+        decode(bytes).map_err(|err| format!("Could not read {}: {}", self, err))
+    }
+
+    /// The path to report in diagnostics about this input.
+    pub fn path(&self) -> PathBuf {
+        match self {
+            Source::Stdin => PathBuf::from("<stdin>"),
+            Source::File(path) => path.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path().display())
+    }
+}
+
+// The path with `.gz` added to its file name
+fn gzipped(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+// Decompress gzipped bytes, recognised by their magic number, and check the text is UTF-8
+fn decode(bytes: Vec<u8>) -> io::Result<String> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut text = String::new();
+        GzDecoder::new(&bytes[..]).read_to_string(&mut text)?;
+        return Ok(text);
+    }
+    String::from_utf8(bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Read an input file, decompressing it if it is gzipped.
+pub fn read_file(path: &Path) -> Result<String, String> {
+    Source::File(path.to_path_buf()).read()
+}
@@ -0,0 +1,685 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::log::{self, Level};
+use aoc_common::{checkpoint, generate, heap, warn, Day, Overrides, Variant};
+use clap::{ArgAction, Parser, Subcommand};
+use input::Source;
+use output::{Answer, Format};
+
+mod bench;
+mod budget;
+mod compare;
+mod crosscheck;
+mod input;
+mod output;
+mod params;
+mod project;
+mod run_all;
+mod scaffold;
+mod verify;
+mod watch;
+
+// Counts nothing unless `--allocs` turns counting on
+#[global_allocator]
+static ALLOCATOR: heap::Counting = heap::Counting;
+
+/// Years with solutions, in the order `verify` and `bench` go through them.
+const YEARS: &[u32] = &[2021, 2022];
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run Advent of Code solutions")]
+struct Cli {
+    /// Log more of what the solutions are doing (-v progress, -vv intermediate
+    /// results, -vvv every step)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Only log errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single solution against an input file, or every solution with --all
+    Run {
+        /// Run every registered part on its day's input.txt, in parallel
+        #[arg(long)]
+        all: bool,
+        /// The year to run (with --all, only run this year)
+        #[arg(long, required_unless_present = "all")]
+        year: Option<u32>,
+        /// The day to run (with --all, only run this day)
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u32>,
+        /// The part to run (with --all, only run this part)
+        #[arg(
+            long,
+            required_unless_present = "all",
+            value_parser = clap::value_parser!(u32).range(1..=2)
+        )]
+        part: Option<u32>,
+        /// Run this alternate implementation instead of the main one
+        #[arg(long, conflicts_with = "all")]
+        variant: Option<String>,
+        /// Override one of the day's parameters, e.g. `--param row=10`; overrides
+        /// for the input in the day's params.toml apply first
+        #[arg(
+            long = "param",
+            value_name = "NAME=VALUE",
+            value_parser = params::parse_override,
+            conflicts_with = "all"
+        )]
+        params: Vec<(String, String)>,
+        /// How to print the answer
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Seconds a part may run before it is cancelled and the best answer found so
+        /// far is reported (defaults to 30 with --all, otherwise no limit)
+        #[arg(long)]
+        timeout: Option<f64>,
+        /// Count heap allocations and report them for each phase alongside its time
+        #[arg(long)]
+        allocs: bool,
+        /// Continue from the checkpoint a long search saved when it was last stopped
+        #[arg(long, conflicts_with = "all")]
+        resume: bool,
+        /// With --all, seconds after which a part is flagged as slow
+        #[arg(long, default_value_t = 1.0)]
+        slow: f64,
+        /// With --all, how many parts to run at once (defaults to the number of cores)
+        #[arg(
+            long = "jobs",
+            short = 'j',
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        workers: Option<usize>,
+        /// Input file, `-` for stdin, or `test` for the day's test_input.txt; gzipped
+        /// files are decompressed (defaults to the day's input.txt)
+        #[arg(conflicts_with = "all")]
+        input: Option<PathBuf>,
+    },
+    /// Run the main implementation and every variant of a part on the same input
+    Compare {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Override one of the day's parameters, e.g. `--param row=10`; overrides
+        /// for the input in the day's params.toml apply first
+        #[arg(
+            long = "param",
+            value_name = "NAME=VALUE",
+            value_parser = params::parse_override
+        )]
+        params: Vec<(String, String)>,
+        /// Input file, `-` for stdin, or `test` for the day's test_input.txt; gzipped
+        /// files are decompressed (defaults to the day's input.txt)
+        input: Option<PathBuf>,
+    },
+    /// Check solutions against the answers recorded in each day's answers.toml
+    Verify {
+        /// Only verify this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u32>,
+        /// Only verify this part
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+    },
+    /// Rebuild and verify a day against its answers.toml whenever its code or inputs change
+    Watch {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        /// Only verify this part
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Milliseconds between checks for changes
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Check solutions against the Python prototypes (`part-N.py`) in each day's directory
+    ///
+    /// Each script is run from its day's directory with the input file as its
+    /// argument, and the last line it prints is taken as its answer.
+    Crosscheck {
+        /// Only check this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only check this day
+        #[arg(long)]
+        day: Option<u32>,
+        /// Input file name within each day's directory
+        #[arg(long, default_value = "input.txt")]
+        input: String,
+        /// Python interpreter to run the prototypes with
+        #[arg(long, default_value = "python3")]
+        python: String,
+    },
+    /// Time the parse and solve phases of each day and compare against the last run
+    ///
+    /// Without --part, only the parts with an answer recorded for the input in
+    /// answers.toml are timed. Exits with an error if any phase regressed.
+    Bench {
+        /// Only benchmark this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only benchmark this day
+        #[arg(long)]
+        day: Option<u32>,
+        /// Only benchmark this part
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Input file name within each day's directory
+        #[arg(long, default_value = "input.txt")]
+        input: String,
+        /// Number of times to run each part
+        #[arg(
+            long,
+            short = 'n',
+            default_value_t = 10,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        iterations: usize,
+        /// Slowdown over the previous run, in percent, that is flagged as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Smallest slowdown, in microseconds, that is flagged as a regression, so
+        /// run-to-run jitter in very fast phases isn't
+        #[arg(long, default_value_t = 10)]
+        min_delta: u64,
+        /// Don't record this run in the days' bench.json
+        #[arg(long)]
+        no_save: bool,
+    },
+    /// Write a random input for a day to stdout, for stress-testing its solution
+    Gen {
+        #[arg(long, default_value_t = 2022)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        /// Seed for the random numbers; the same seed writes the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big to make the input, e.g. lines, items or rows depending on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Create a new day from the template and register it with the runner
+    New {
+        #[arg(long)]
+        year: u32,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Write rust-project.json for rust-analyzer from the crates in the repository
+    RustProject,
+}
+
+// The solutions for a given year
+fn year_solutions(year: u32) -> Option<&'static [Day]> {
+    match year {
+        2021 => Some(aoc_2021::SOLUTIONS),
+        2022 => Some(aoc_2022::SOLUTIONS),
+        _ => None,
+    }
+}
+
+// Look up the solution for a given year and day
+fn find_solution(year: u32, day: u32) -> Option<&'static Day> {
+    year_solutions(year)?
+        .iter()
+        .find(|solution| solution.day == day)
+}
+
+// Every solution matching the optional year and day filters, with its year
+fn select_solutions(year: Option<u32>, day: Option<u32>) -> Vec<(u32, &'static Day)> {
+    YEARS
+        .iter()
+        .filter(|y| year.is_none_or(|year| year == **y))
+        .flat_map(|&y| {
+            year_solutions(y)
+                .unwrap()
+                .iter()
+                .map(move |solution| (y, solution))
+        })
+        .filter(|(_, solution)| day.is_none_or(|day| day == solution.day))
+        .collect()
+}
+
+fn exit_on_error<T>(result: Result<T, String>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    })
+}
+
+// The root of the repository, one level above the runner's crate
+fn repo_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+// The crate directory for a year, e.g. `2022`
+fn year_dir(year: u32) -> PathBuf {
+    repo_root().join(year.to_string())
+}
+
+// The directory holding the inputs for a day, e.g. `2022/day-16`
+fn day_dir(year: u32, day: u32) -> PathBuf {
+    year_dir(year).join(format!("day-{}", day))
+}
+
+// A day's solution with its input read and its parameter overrides gathered
+struct Loaded {
+    solution: &'static Day,
+    input: Source,
+    contents: String,
+    overrides: Overrides,
+}
+
+// Look up a day's solution, read its input and gather the overrides for it, exiting
+// if any of those fail. Overrides from the command line win over the day's params.toml.
+fn load_day(year: u32, day: u32, input: Option<PathBuf>, cli: Vec<(String, String)>) -> Loaded {
+    let solution = find_solution(year, day).unwrap_or_else(|| {
+        eprintln!("No solution for {} day {}", year, day);
+        process::exit(1);
+    });
+    let dir = day_dir(year, day);
+    let input = Source::resolve(&dir, input.as_deref());
+    let contents = exit_on_error(input.read());
+    let mut overrides = match &input {
+        Source::File(path) => {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            exit_on_error(params::load(&dir, &name))
+        }
+        Source::Stdin => Overrides::new(),
+    };
+    overrides.extend(cli);
+    Loaded {
+        solution,
+        input,
+        contents,
+        overrides,
+    }
+}
+
+// Where a part keeps its checkpoint, fingerprinted by its input and parameters so a
+// checkpoint is only resumed by the same puzzle
+fn checkpoint_store(
+    year: u32,
+    part: u32,
+    variant: Option<&str>,
+    loaded: &Loaded,
+    resume: bool,
+) -> checkpoint::Store {
+    let mut name = format!("{}-day-{}-part-{}", year, loaded.solution.day, part);
+    if let Some(variant) = variant {
+        name = format!("{}-{}", name, variant);
+    }
+    let mut hasher = DefaultHasher::new();
+    (&loaded.contents, &loaded.overrides).hash(&mut hasher);
+    checkpoint::Store {
+        path: repo_root()
+            .join("target")
+            .join("checkpoints")
+            .join(format!("{}.json", name)),
+        fingerprint: format!("{:016x}", hasher.finish()),
+        resume,
+    }
+}
+
+// Look up a named variant of a part, exiting with the available names if it is missing
+fn find_variant(solution: &Day, year: u32, part: u32, name: &str) -> &'static Variant {
+    if let Some(variant) = solution.variants(part).find(|variant| variant.name == name) {
+        return variant;
+    }
+    let names = solution
+        .variants(part)
+        .map(|variant| variant.name)
+        .collect::<Vec<_>>();
+    eprintln!(
+        "No variant `{}` for {} day {} part {} (available: {})",
+        name,
+        year,
+        solution.day,
+        part,
+        if names.is_empty() {
+            "none".to_string()
+        } else {
+            names.join(", ")
+        }
+    );
+    process::exit(1);
+}
+
+fn main() {
+    let cli = Cli::parse();
+    log::set_level(match (cli.quiet, cli.verbose) {
+        (true, _) => Level::Error,
+        (false, 0) => Level::Warn,
+        (false, 1) => Level::Info,
+        (false, 2) => Level::Debug,
+        (false, _) => Level::Trace,
+    });
+
+    match cli.command {
+        Command::Run {
+            all: true,
+            year,
+            day,
+            part,
+            format,
+            timeout,
+            allocs,
+            slow,
+            workers,
+            ..
+        } => {
+            if allocs {
+                heap::enable();
+            }
+            let jobs = select_solutions(year, day)
+                .into_iter()
+                .flat_map(|(year, solution)| {
+                    (1..=2)
+                        .filter(move |p| part.is_none_or(|part| part == *p))
+                        .map(move |part| {
+                            let dir = day_dir(year, solution.day);
+                            run_all::Job {
+                                year,
+                                solution,
+                                part,
+                                input: Source::resolve(&dir, None),
+                                overrides: exit_on_error(params::load(&dir, "input.txt")),
+                            }
+                        })
+                })
+                .collect::<Vec<_>>();
+            let options = run_all::Options {
+                workers: workers.unwrap_or_else(|| {
+                    thread::available_parallelism().map_or(1, |cores| cores.get())
+                }),
+                timeout: Duration::from_secs_f64(timeout.unwrap_or(30.0)),
+            };
+            let statuses = run_all::run_all(&jobs, &options);
+            let solved = match format {
+                Format::Text => run_all::report(
+                    &jobs,
+                    &statuses,
+                    options.timeout,
+                    Duration::from_secs_f64(slow),
+                ),
+                Format::Json => run_all::print_json(&jobs, &statuses),
+            };
+            // Abandoned threads of timed out parts end with the process
+            process::exit(if solved { 0 } else { 1 });
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            variant,
+            params,
+            format,
+            timeout,
+            allocs,
+            resume,
+            input,
+            ..
+        } => {
+            // Without --all, clap requires the year, day and part
+            let (year, day, part) = (year.unwrap(), day.unwrap(), part.unwrap());
+            if allocs {
+                heap::enable();
+            }
+            let loaded = load_day(year, day, input, params);
+            let (contents, overrides) = (loaded.contents.clone(), loaded.overrides.clone());
+            let solution = loaded.solution;
+            let found = variant
+                .as_deref()
+                .map(|name| find_variant(solution, year, part, name));
+            let store = checkpoint_store(year, part, variant.as_deref(), &loaded, resume);
+            let thread_store = store.clone();
+            let outcome = budget::solve_within(timeout.map(Duration::from_secs_f64), move || {
+                checkpoint::with_store(&thread_store, || match found {
+                    Some(variant) => {
+                        let start = Instant::now();
+                        let (result, allocs) =
+                            heap::measure(|| (variant.run)(&contents, &overrides));
+                        result.map(|answer| (answer, None, start.elapsed(), None, allocs))
+                    }
+                    None => (solution.time)(&contents, part, &overrides).map(|(answer, times)| {
+                        (
+                            answer,
+                            Some(times.parse),
+                            times.solve,
+                            times.parse_allocs,
+                            times.solve_allocs,
+                        )
+                    }),
+                })
+            });
+            let (result, partial) = match outcome {
+                budget::Outcome::Finished(result) => (result, false),
+                budget::Outcome::Partial(result) => (result, true),
+                budget::Outcome::Panicked(message) => {
+                    eprintln!("The solution panicked: {}", message);
+                    process::exit(1);
+                }
+                budget::Outcome::Abandoned => {
+                    eprintln!(
+                        "Timed out, and the solution did not stop within {:.0?} of being cancelled",
+                        budget::GRACE
+                    );
+                    process::exit(1);
+                }
+            };
+            let (answer, parse, solve, parse_allocs, solve_allocs) = result.unwrap_or_else(|err| {
+                eprintln!("{}", err.with_file(loaded.input.path()));
+                process::exit(1);
+            });
+            if partial {
+                warn!(
+                    "Timed out, so this is only the best answer found before cancelling; \
+                     run again with --resume to continue the search"
+                );
+            } else {
+                // The search ran to the end, so there is nothing left to resume
+                store.clear();
+            }
+            output::print(
+                format,
+                &Answer {
+                    year,
+                    day,
+                    part,
+                    variant: variant.as_deref(),
+                    answer: &answer,
+                    partial,
+                    parse_ms: parse.map(output::millis),
+                    solve_ms: output::millis(solve),
+                    parse_allocs,
+                    solve_allocs,
+                },
+            );
+            if partial {
+                process::exit(1);
+            }
+        }
+        Command::Compare {
+            year,
+            day,
+            part,
+            params,
+            input,
+        } => {
+            let loaded = load_day(year, day, input, params);
+            let outcomes =
+                compare::compare(loaded.solution, part, &loaded.contents, &loaded.overrides);
+            for outcome in &outcomes {
+                if let Err(err) = &outcome.answer {
+                    eprintln!("{}", err.clone().with_file(loaded.input.path()));
+                }
+            }
+            if !compare::report(&outcomes) {
+                process::exit(1);
+            }
+        }
+        Command::Verify { year, day, part } => {
+            let parts = part.map_or(vec![1, 2], |part| vec![part]);
+            let mut summary = verify::Summary::default();
+            for (y, solution) in select_solutions(year, day) {
+                let dir = day_dir(y, solution.day);
+                summary.add(exit_on_error(verify::verify_day(
+                    y,
+                    solution.day,
+                    &dir,
+                    &parts,
+                    solution.run,
+                )));
+            }
+            println!("{} passed, {} failed", summary.passed, summary.failed);
+            if summary.failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Watch {
+            year,
+            day,
+            part,
+            interval,
+        } => {
+            if find_solution(year, day).is_none() {
+                eprintln!("No solution for {} day {}", year, day);
+                process::exit(1);
+            }
+            let options = watch::Options {
+                year,
+                day,
+                part,
+                interval: Duration::from_millis(interval),
+            };
+            exit_on_error(watch::watch(
+                repo_root(),
+                &year_dir(year),
+                &day_dir(year, day),
+                &options,
+            ));
+        }
+        Command::Crosscheck {
+            year,
+            day,
+            input,
+            python,
+        } => {
+            let mut summary = verify::Summary::default();
+            for (y, solution) in select_solutions(year, day) {
+                let dir = day_dir(y, solution.day);
+                summary.add(exit_on_error(crosscheck::crosscheck_day(
+                    y,
+                    solution.day,
+                    &dir,
+                    &input,
+                    &python,
+                    solution.run,
+                )));
+            }
+            println!("{} passed, {} failed", summary.passed, summary.failed);
+            if summary.failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            part,
+            input,
+            iterations,
+            threshold,
+            min_delta,
+            no_save,
+        } => {
+            let options = bench::Options {
+                iterations,
+                threshold: threshold / 100.0,
+                min_delta: Duration::from_micros(min_delta),
+                save: !no_save,
+            };
+            let mut regressions = 0;
+            for (y, solution) in select_solutions(year, day) {
+                let dir = day_dir(y, solution.day);
+                let parts = match part {
+                    Some(part) => vec![part],
+                    None => {
+                        let manifest = exit_on_error(verify::load_manifest(&dir));
+                        match manifest.as_ref().and_then(|manifest| manifest.get(&input)) {
+                            Some(expected) => {
+                                (1..=2).filter(|p| expected.part(*p).is_some()).collect()
+                            }
+                            None => vec![1, 2],
+                        }
+                    }
+                };
+                let label = format!("{} day {}", y, solution.day);
+                let result = bench::bench_and_record(
+                    &label,
+                    solution,
+                    &dir,
+                    &dir.join(&input),
+                    &parts,
+                    &options,
+                );
+                if exit_on_error(result) {
+                    regressions += 1;
+                }
+            }
+            if regressions > 0 {
+                println!("{} day(s) regressed", regressions);
+                process::exit(1);
+            }
+        }
+        Command::Gen {
+            year,
+            day,
+            seed,
+            size,
+        } => {
+            let solution = find_solution(year, day).unwrap_or_else(|| {
+                eprintln!("No solution for {} day {}", year, day);
+                process::exit(1);
+            });
+            let generator = solution.generator.unwrap_or_else(|| {
+                eprintln!("No input generator for {} day {}", year, day);
+                process::exit(1);
+            });
+            print!("{}", generator(&mut generate::rng(seed), size));
+        }
+        Command::New { year, day } => {
+            if year_solutions(year).is_none() {
+                eprintln!("No crate for {}", year);
+                process::exit(1);
+            }
+            exit_on_error(scaffold::new_day(&year_dir(year), day));
+        }
+        Command::RustProject => {
+            exit_on_error(project::write(repo_root()));
+        }
+    }
+}
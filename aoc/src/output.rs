@@ -0,0 +1,99 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! How the runner prints answers.
+
+use std::time::Duration;
+
+use aoc_common::Allocs;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// Output format for answers.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Just the answer, with allocations on stderr if they were counted
+    Text,
+    /// One JSON object per line with the answer, its timings and its allocations
+    Json,
+}
+
+/// The answer to one part, with how long it took.
+#[derive(Serialize)]
+pub struct Answer<'a> {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<&'a str>,
+    pub answer: &'a str,
+    /// The part was cancelled at its timeout, so this is only the best answer so far.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub partial: bool,
+    /// Variants parse and solve in one call, so only their total is known.
+    pub parse_ms: Option<f64>,
+    pub solve_ms: f64,
+    /// Only present when allocations are counted, and never for variants.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_allocs: Option<Allocs>,
+    /// Only present when allocations are counted. For variants this covers parsing too.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_allocs: Option<Allocs>,
+}
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// A byte count in the largest binary unit that keeps it above one, e.g. `12.3 MiB`.
+pub fn bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// One phase's allocations on a line, e.g. `1204 allocations, 3.2 MiB allocated, 1.1 MiB peak`.
+pub fn allocs(allocs: &Allocs) -> String {
+    format!(
+        "{} allocations, {} allocated, {} peak",
+        allocs.allocations,
+        bytes(allocs.bytes),
+        bytes(allocs.peak_bytes)
+    )
+}
+
+// Print an answer in the given format
+pub fn print(format: Format, answer: &Answer) {
+    match format {
+        Format::Text => {
+            println!("{}", answer.answer);
+            // Variants only have a total, which is reported as the solve phase
+            if let Some(parse) = &answer.parse_allocs {
+                eprintln!(
+                    "parse: {:.2}ms, {}",
+                    answer.parse_ms.unwrap_or_default(),
+                    allocs(parse)
+                );
+            }
+            if let Some(solve) = &answer.solve_allocs {
+                let phase = if answer.parse_ms.is_some() {
+                    "solve"
+                } else {
+                    "total"
+                };
+                eprintln!("{}: {:.2}ms, {}", phase, answer.solve_ms, allocs(solve));
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string(answer).unwrap()),
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Parameter overrides for each input, from a day's `params.toml` and the
+//! command line.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use aoc_common::Overrides;
+
+/// Name of the parameter overrides inside a day's directory.
+pub const CONFIG: &str = "params.toml";
+
+// Read a day's overrides for the named input file. A gzipped input uses the
+// overrides of the file it was compressed from.
+pub fn load(dir: &Path, input: &str) -> Result<Overrides, String> {
+    let path = dir.join(CONFIG);
+    if !path.exists() {
+        return Ok(Overrides::new());
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+    let mut config: BTreeMap<String, toml::Table> = toml::from_str(&contents)
+        .map_err(|err| format!("Could not parse {}: {}", path.display(), err))?;
+
+    let input = input.strip_suffix(".gz").unwrap_or(input);
+    let overrides = config.remove(input).unwrap_or_default();
+    Ok(overrides
+        .into_iter()
+        .map(|(name, value)| match value {
+            toml::Value::String(value) => (name, value),
+            value => (name, value.to_string()),
+        })
+        .collect())
+}
+
+// Parse a `NAME=VALUE` override from the command line
+pub fn parse_override(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("expected NAME=VALUE, found `{}`", arg)),
+    }
+}
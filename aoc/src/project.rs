@@ -0,0 +1,311 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Generating `rust-project.json` for rust-analyzer from `cargo metadata`.
+//!
+//! The workspace and every Cargo package outside it (such as the day 14
+//! visualizers) are loaded, so the project file covers each year's crate with
+//! all of its day and variant modules, the runner, and their dependencies.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use aoc_common::warn;
+use serde::{Deserialize, Serialize};
+
+/// Name of the generated project file, written at the repository root.
+pub const PROJECT_FILE: &str = "rust-project.json";
+
+// The subset of `cargo metadata` output needed to build the crate graph
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<Package>,
+    workspace_members: Vec<String>,
+    resolve: Resolve,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    id: String,
+    edition: String,
+    manifest_path: PathBuf,
+    targets: Vec<Target>,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name: String,
+    kind: Vec<String>,
+    src_path: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct Resolve {
+    nodes: Vec<Node>,
+}
+
+#[derive(Deserialize)]
+struct Node {
+    id: String,
+    deps: Vec<NodeDep>,
+    features: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct NodeDep {
+    name: String,
+    pkg: String,
+    dep_kinds: Vec<DepKind>,
+}
+
+#[derive(Deserialize)]
+struct DepKind {
+    kind: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Project {
+    sysroot: PathBuf,
+    sysroot_src: PathBuf,
+    crates: Vec<Crate>,
+}
+
+#[derive(Serialize)]
+struct Crate {
+    display_name: String,
+    root_module: PathBuf,
+    edition: String,
+    deps: Vec<Dep>,
+    cfg: Vec<String>,
+    is_workspace_member: bool,
+    is_proc_macro: bool,
+}
+
+#[derive(Serialize)]
+struct Dep {
+    #[serde(rename = "crate")]
+    krate: usize,
+    name: String,
+}
+
+// Run a command and return its trimmed standard output
+fn command_output(command: &mut Command) -> Result<String, String> {
+    let output = command
+        .output()
+        .map_err(|err| format!("Could not run {:?}: {}", command, err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{:?} failed: {}",
+            command,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn rustc() -> Command {
+    Command::new(std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into()))
+}
+
+// The host target triple, so only dependencies used on this platform are listed
+fn host_triple() -> Result<String, String> {
+    let version = command_output(rustc().arg("-vV"))?;
+    version
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(|host| host.to_string())
+        .ok_or_else(|| "Could not find the host in `rustc -vV`".to_string())
+}
+
+fn cargo_metadata(manifest: &Path, host: &str) -> Result<Metadata, String> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = command_output(
+        Command::new(cargo)
+            .args([
+                "metadata",
+                "--format-version",
+                "1",
+                "--filter-platform",
+                host,
+            ])
+            .arg("--manifest-path")
+            .arg(manifest),
+    )?;
+    serde_json::from_str(&output).map_err(|err| {
+        format!(
+            "Could not parse cargo metadata for {}: {}",
+            manifest.display(),
+            err
+        )
+    })
+}
+
+// Every Cargo.toml under the root, skipping build output and hidden directories
+fn find_manifests(dir: &Path, manifests: &mut Vec<PathBuf>) -> Result<(), String> {
+    let mut entries = fs::read_dir(dir)
+        .map_err(|err| format!("Could not read {}: {}", dir.display(), err))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    for path in entries {
+        let name = path.file_name().unwrap().to_string_lossy();
+        if path.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                find_manifests(&path, manifests)?;
+            }
+        } else if name == "Cargo.toml" {
+            manifests.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_lib_target(target: &Target) -> bool {
+    target.kind.iter().any(|kind| {
+        matches!(
+            kind.as_str(),
+            "lib" | "rlib" | "dylib" | "cdylib" | "staticlib" | "proc-macro"
+        )
+    })
+}
+
+// Targets that rust-analyzer should treat as crates of their own. Binaries
+// are only needed for our own packages.
+fn is_crate_target(target: &Target, workspace_member: bool) -> bool {
+    is_lib_target(target) || (workspace_member && target.kind.iter().any(|kind| kind == "bin"))
+}
+
+// Build the project from the workspace at the root and every package outside it
+pub fn generate(root: &Path) -> Result<Project, String> {
+    // Cargo reports canonical paths, so compare manifests in the same form
+    let root = fs::canonicalize(root)
+        .map_err(|err| format!("Could not resolve {}: {}", root.display(), err))?;
+    let sysroot = PathBuf::from(command_output(rustc().args(["--print", "sysroot"]))?);
+    let host = host_triple()?;
+
+    // Load each manifest that isn't already part of a workspace loaded before it
+    let mut manifests = vec![root.join("Cargo.toml")];
+    find_manifests(&root, &mut manifests)?;
+    let mut packages = Vec::new();
+    let mut nodes = HashMap::new();
+    let mut members = BTreeSet::new();
+    let mut loaded = BTreeSet::new();
+    for manifest in manifests {
+        if loaded.contains(&manifest) {
+            continue;
+        }
+        let metadata = cargo_metadata(&manifest, &host)?;
+        for package in &metadata.packages {
+            if metadata.workspace_members.contains(&package.id) {
+                loaded.insert(package.manifest_path.clone());
+            }
+        }
+        loaded.insert(manifest);
+        members.extend(metadata.workspace_members);
+        for node in metadata.resolve.nodes {
+            nodes.entry(node.id.clone()).or_insert(node);
+        }
+        for package in metadata.packages {
+            if !packages.iter().any(|p: &Package| p.id == package.id) {
+                packages.push(package);
+            }
+        }
+    }
+
+    // Number the crates first so dependencies can refer to them by index
+    let mut crates = Vec::new();
+    let mut lib_index = HashMap::new();
+    for package in &packages {
+        let member = members.contains(&package.id);
+        for target in package
+            .targets
+            .iter()
+            .filter(|t| is_crate_target(t, member))
+        {
+            if is_lib_target(target) {
+                let name = target.name.replace('-', "_");
+                lib_index.insert(package.id.as_str(), (crates.len(), name));
+            }
+            crates.push((package, target));
+        }
+    }
+
+    let crates = crates
+        .into_iter()
+        .map(|(package, target)| {
+            let node = &nodes[&package.id];
+            let mut deps = node
+                .deps
+                .iter()
+                .filter(|dep| dep.dep_kinds.iter().any(|kind| kind.kind.is_none()))
+                .filter_map(|dep| {
+                    lib_index.get(dep.pkg.as_str()).map(|(krate, _)| Dep {
+                        krate: *krate,
+                        name: dep.name.clone(),
+                    })
+                })
+                .collect::<Vec<_>>();
+            // Binaries can use the library of their own package
+            if !is_lib_target(target) {
+                if let Some((krate, name)) = lib_index.get(package.id.as_str()) {
+                    deps.push(Dep {
+                        krate: *krate,
+                        name: name.clone(),
+                    });
+                }
+            }
+            Crate {
+                display_name: target.name.clone(),
+                root_module: target.src_path.clone(),
+                edition: package.edition.clone(),
+                deps,
+                cfg: node
+                    .features
+                    .iter()
+                    .map(|feature| format!("feature=\"{}\"", feature))
+                    .collect(),
+                is_workspace_member: members.contains(&package.id),
+                is_proc_macro: target.kind.iter().any(|kind| kind == "proc-macro"),
+            }
+        })
+        .collect();
+
+    Ok(Project {
+        sysroot_src: sysroot.join("lib/rustlib/src/rust/library"),
+        sysroot,
+        crates,
+    })
+}
+
+// Generate the project and write it to the root
+pub fn write(root: &Path) -> Result<(), String> {
+    let project = generate(root)?;
+    if !project.sysroot_src.exists() {
+        warn!(
+            "{} does not exist; install it with `rustup component add rust-src`",
+            project.sysroot_src.display()
+        );
+    }
+    let path = root.join(PROJECT_FILE);
+    let contents = serde_json::to_string_pretty(&project).unwrap();
+    fs::write(&path, contents + "\n")
+        .map_err(|err| format!("Could not write {}: {}", path.display(), err))?;
+    let members = project
+        .crates
+        .iter()
+        .filter(|krate| krate.is_workspace_member)
+        .count();
+    println!(
+        "Wrote {} with {} crates ({} from this repository)",
+        path.display(),
+        project.crates.len(),
+        members
+    );
+    Ok(())
+}
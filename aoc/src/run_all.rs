@@ -0,0 +1,273 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Running every registered part at once, spread across the available cores.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::{heap, CancelToken, Day, Overrides, PhaseTimes};
+
+use crate::budget::{self, GRACE};
+use crate::input::Source;
+use crate::output::{self, Answer, Format};
+
+/// One part of one day to run.
+#[derive(Clone)]
+pub struct Job {
+    pub year: u32,
+    pub solution: &'static Day,
+    pub part: u32,
+    pub input: Source,
+    pub overrides: Overrides,
+}
+
+/// How a job ended.
+pub enum Status {
+    Solved(String, PhaseTimes),
+    /// Cancelled at the deadline, with the best answer found before stopping.
+    Partial(String, PhaseTimes),
+    Failed(String),
+    TimedOut,
+}
+
+/// Limits for a run over many jobs.
+pub struct Options {
+    /// How many jobs run at once.
+    pub workers: usize,
+    /// Wall-clock budget for each job.
+    pub timeout: Duration,
+}
+
+// Read a job's input and solve it
+fn run_job(job: &Job) -> Result<(String, PhaseTimes), String> {
+    let contents = job.input.read()?;
+    (job.solution.time)(&contents, job.part, &job.overrides).map_err(|err| {
+        err.with_file(job.input.path())
+            .to_string()
+            .replace('\n', " ")
+    })
+}
+
+// A job that has been started, and when it was cancelled if it ran over its budget
+struct Running {
+    started: Instant,
+    token: CancelToken,
+    cancelled: Option<Instant>,
+}
+
+impl Running {
+    // When the job is next due to be cancelled or abandoned
+    fn deadline(&self, timeout: Duration) -> Instant {
+        match self.cancelled {
+            Some(cancelled) => cancelled + GRACE,
+            None => self.started + timeout,
+        }
+    }
+}
+
+// Run every job, at most `workers` at a time, returning their statuses in job order.
+// A job over its budget is cancelled, and reported with the best answer it returns
+// within the grace period. One that doesn't stop by then is reported as timed out
+// and its thread is abandoned, still holding its core, until the process exits.
+pub fn run_all(jobs: &[Job], options: &Options) -> Vec<Status> {
+    let mut statuses = jobs.iter().map(|_| None).collect::<Vec<_>>();
+    let mut running = HashMap::new();
+    let mut next = 0;
+    let (sender, receiver) = mpsc::channel();
+
+    while next < jobs.len() || !running.is_empty() {
+        // Start jobs until every worker is busy
+        while next < jobs.len() && running.len() < options.workers {
+            let job = jobs[next].clone();
+            let sender = sender.clone();
+            let token = CancelToken::new();
+            let thread_token = token.clone();
+            thread::spawn(move || {
+                let result = budget::solve_with_token(&thread_token, || run_job(&job))
+                    .unwrap_or_else(|message| Err(format!("panicked: {}", message)));
+                // The receiver is gone if the run finished without this job
+                let _ = sender.send((next, result));
+            });
+            running.insert(
+                next,
+                Running {
+                    started: Instant::now(),
+                    token,
+                    cancelled: None,
+                },
+            );
+            next += 1;
+        }
+
+        // Wait for a result, or until the job closest to its deadline runs out of time
+        let wait = running
+            .values()
+            .map(|job| {
+                job.deadline(options.timeout)
+                    .saturating_duration_since(Instant::now())
+            })
+            .min()
+            .unwrap();
+        match receiver.recv_timeout(wait) {
+            Ok((index, result)) => {
+                // Results of jobs that were already abandoned are dropped
+                if let Some(job) = running.remove(&index) {
+                    statuses[index] = Some(match result {
+                        Ok((answer, times)) if job.cancelled.is_some() => {
+                            Status::Partial(answer, times)
+                        }
+                        Ok((answer, times)) => Status::Solved(answer, times),
+                        Err(err) => Status::Failed(err),
+                    });
+                }
+            }
+            Err(_) => {
+                let now = Instant::now();
+                running.retain(|&index, job| {
+                    if job.deadline(options.timeout) > now {
+                        return true;
+                    }
+                    if job.cancelled.is_none() {
+                        job.token.cancel();
+                        job.cancelled = Some(now);
+                        return true;
+                    }
+                    statuses[index] = Some(Status::TimedOut);
+                    false
+                });
+            }
+        }
+    }
+
+    statuses.into_iter().map(Option::unwrap).collect()
+}
+
+// Print the answer of every solved job as JSON, and the rest to stderr. Returns
+// whether every job was solved.
+pub fn print_json(jobs: &[Job], statuses: &[Status]) -> bool {
+    let mut solved = true;
+    for (job, status) in jobs.iter().zip(statuses) {
+        let label = format!("{} day {} part {}", job.year, job.solution.day, job.part);
+        match status {
+            Status::Solved(answer, times) => output::print(
+                Format::Json,
+                &Answer {
+                    year: job.year,
+                    day: job.solution.day,
+                    part: job.part,
+                    variant: None,
+                    answer,
+                    partial: false,
+                    parse_ms: Some(output::millis(times.parse)),
+                    solve_ms: output::millis(times.solve),
+                    parse_allocs: times.parse_allocs,
+                    solve_allocs: times.solve_allocs,
+                },
+            ),
+            Status::Partial(answer, times) => {
+                output::print(
+                    Format::Json,
+                    &Answer {
+                        year: job.year,
+                        day: job.solution.day,
+                        part: job.part,
+                        variant: None,
+                        answer,
+                        partial: true,
+                        parse_ms: Some(output::millis(times.parse)),
+                        solve_ms: output::millis(times.solve),
+                        parse_allocs: times.parse_allocs,
+                        solve_allocs: times.solve_allocs,
+                    },
+                );
+                solved = false;
+            }
+            Status::Failed(err) => {
+                eprintln!("{}: {}", label, err);
+                solved = false;
+            }
+            Status::TimedOut => {
+                eprintln!("{}: timed out", label);
+                solved = false;
+            }
+        }
+    }
+    solved
+}
+
+// Print a table of every job with its status, time and answer, flagging the ones
+// slower than `slow`, with what each allocated if allocations were counted. Returns
+// whether every job was solved.
+pub fn report(jobs: &[Job], statuses: &[Status], timeout: Duration, slow: Duration) -> bool {
+    let mut solved = 0;
+    let mut slow_jobs = 0;
+    let allocs = heap::enabled();
+    let header = if allocs {
+        format!("  {:>9}  {:>10}  {:>10}", "allocs", "allocated", "peak")
+    } else {
+        String::new()
+    };
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<7}  {:>10}{}  answer",
+        "year", "day", "part", "status", "time", header
+    );
+    for (job, status) in jobs.iter().zip(statuses) {
+        // Both phases together, for the parts that returned an answer
+        let counted = match status {
+            Status::Solved(_, times) | Status::Partial(_, times) => {
+                times.parse_allocs.zip(times.solve_allocs)
+            }
+            _ => None,
+        }
+        .map(|(parse, solve)| parse.then(solve));
+        let counted = match counted {
+            Some(counted) => format!(
+                "  {:>9}  {:>10}  {:>10}",
+                counted.allocations,
+                output::bytes(counted.bytes),
+                output::bytes(counted.peak_bytes)
+            ),
+            None if allocs => format!("  {:>9}  {:>10}  {:>10}", "-", "-", "-"),
+            None => String::new(),
+        };
+        let (status, time, answer) = match status {
+            Status::Solved(answer, times) => {
+                solved += 1;
+                let total = times.parse + times.solve;
+                let status = if total > slow {
+                    slow_jobs += 1;
+                    "SLOW"
+                } else {
+                    "ok"
+                };
+                (status, format!("{:.2?}", total), answer.clone())
+            }
+            Status::Partial(answer, _) => ("PARTIAL", format!(">{:.0?}", timeout), answer.clone()),
+            Status::Failed(err) => ("ERROR", "-".to_string(), err.clone()),
+            Status::TimedOut => ("TIMEOUT", format!(">{:.0?}", timeout), String::new()),
+        };
+        // Multi-line answers are shown from the line after the table row
+        let answer = if answer.contains('\n') {
+            format!("\n{}", answer)
+        } else {
+            answer
+        };
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:<7}  {:>10}{}  {}",
+            job.year, job.solution.day, job.part, status, time, counted, answer
+        );
+    }
+    println!(
+        "{} of {} solved, {} slower than {:.0?}",
+        solved,
+        jobs.len(),
+        slow_jobs,
+        slow
+    );
+    solved == jobs.len()
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Creating a new day from the templates and registering it with its year's crate.
+
+use std::fs;
+use std::path::Path;
+
+use aoc_common::warn;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const ANSWERS_TEMPLATE: &str = include_str!("../templates/answers.toml");
+
+// Everything between the opening and closing brackets of the SOLUTIONS table
+const SOLUTIONS_START: &str = "pub const SOLUTIONS: &[Day] = &[";
+const SOLUTIONS_END: &str = "];";
+
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    if path.exists() {
+        warn!("Keeping existing {}", path.display());
+        return Ok(());
+    }
+    fs::write(path, contents)
+        .map_err(|err| format!("Could not write {}: {}", path.display(), err))?;
+    println!("Created {}", path.display());
+    Ok(())
+}
+
+// The day number of a `pub mod dayNN;` line
+fn module_day(line: &str) -> Option<u32> {
+    line.strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+// Add the module declaration and SOLUTIONS entry for a day to a year's lib.rs
+fn register(lib: &str, day: u32) -> Result<String, String> {
+    let lines = lib.lines().collect::<Vec<_>>();
+
+    // The module goes after the last day before it, or before the first one
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| module_day(line).map(|d| (i, d)))
+        .collect::<Vec<_>>();
+    if modules.iter().any(|(_, d)| *d == day) {
+        return Err(format!("Day {} is already registered", day));
+    }
+    let path = format!("#[path = \"day-{}/mod.rs\"]", day);
+    let module = format!("pub mod day{:02};", day);
+    let mut output = lines
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    match modules.iter().find(|(_, d)| *d > day) {
+        // Before the `#[path]` attribute of the next day
+        Some((i, _)) => {
+            output.splice(i - 1..i - 1, [path, module, String::new()]);
+        }
+        // After the last day
+        None => {
+            let (i, _) = modules.last().ok_or("No day modules found")?;
+            output.splice(i + 1..i + 1, [String::new(), path, module]);
+        }
+    }
+    let lib = output.join("\n") + "\n";
+
+    // Rewrite the SOLUTIONS table with the new day in order
+    let start = lib
+        .find(SOLUTIONS_START)
+        .ok_or("No SOLUTIONS table found")?
+        + SOLUTIONS_START.len();
+    let end = start
+        + lib[start..]
+            .find(SOLUTIONS_END)
+            .ok_or("Unterminated SOLUTIONS table")?;
+    let mut entries = lib[start..end]
+        .split("),")
+        .map(|entry| entry.trim().trim_end_matches(')'))
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let day = entry
+                .rsplit('(')
+                .next()
+                .and_then(|day| day.parse::<u32>().ok())
+                .ok_or_else(|| format!("Could not read SOLUTIONS entry `{}`", entry))?;
+            Ok((day, format!("{})", entry)))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    entries.push((
+        day,
+        format!("Day::new::<day{:02}::Day{:02}>({})", day, day, day),
+    ));
+    entries.sort_by_key(|(day, _)| *day);
+    let table = entries
+        .iter()
+        .map(|(_, entry)| format!("\n    {},", entry))
+        .collect::<String>();
+
+    Ok(format!("{}{}\n{}", &lib[..start], table, &lib[end..]))
+}
+
+// Create a day's module, inputs and answers manifest, and register it in the year's lib.rs
+pub fn new_day(year_dir: &Path, day: u32) -> Result<(), String> {
+    let lib_path = year_dir.join("lib.rs");
+    let lib = fs::read_to_string(&lib_path)
+        .map_err(|err| format!("Could not read {}: {}", lib_path.display(), err))?;
+
+    let dir = year_dir.join(format!("day-{}", day));
+    if dir.join("mod.rs").exists() {
+        return Err(format!("{} already exists", dir.join("mod.rs").display()));
+    }
+    let lib = register(&lib, day)?;
+
+    fs::create_dir_all(&dir)
+        .map_err(|err| format!("Could not create {}: {}", dir.display(), err))?;
+    write_new(
+        &dir.join("mod.rs"),
+        &DAY_TEMPLATE.replace("DayNN", &format!("Day{:02}", day)),
+    )?;
+    write_new(&dir.join("input.txt"), "")?;
+    write_new(&dir.join("test_input.txt"), "")?;
+    write_new(&dir.join(super::verify::MANIFEST), ANSWERS_TEMPLATE)?;
+
+    fs::write(&lib_path, lib)
+        .map_err(|err| format!("Could not write {}: {}", lib_path.display(), err))?;
+    println!("Registered day {} in {}", day, lib_path.display());
+    Ok(())
+}
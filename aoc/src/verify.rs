@@ -0,0 +1,143 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Checking solutions against the expected answers in each day's `answers.toml`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use aoc_common::Runner;
+use serde::Deserialize;
+
+use crate::{input, params};
+
+/// Name of the answers manifest inside a day's directory.
+pub const MANIFEST: &str = "answers.toml";
+
+/// The expected answers for one input file. A part left out is not checked.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// A day's answers manifest, keyed by input file name relative to the day's directory.
+pub type Manifest = BTreeMap<String, Expected>;
+
+// Read the manifest for a day, if it has one
+pub fn load_manifest(dir: &Path) -> Result<Option<Manifest>, String> {
+    let path = dir.join(MANIFEST);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+    toml::from_str(&contents)
+        .map(Some)
+        .map_err(|err| format!("Could not parse {}: {}", path.display(), err))
+}
+
+/// Tally of the checks made by `verify_day`.
+#[derive(Default)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, other: Summary) {
+        self.passed += other.passed;
+        self.failed += other.failed;
+    }
+}
+
+// Print the lines that differ between the expected and actual answers
+pub fn print_diff(expected: &str, actual: &str) {
+    if !expected.contains('\n') && !actual.contains('\n') {
+        println!("    expected: {}", expected);
+        println!("    actual:   {}", actual);
+        return;
+    }
+    let expected_lines = expected.lines().collect::<Vec<_>>();
+    let actual_lines = actual.lines().collect::<Vec<_>>();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let e = expected_lines.get(i);
+        let a = actual_lines.get(i);
+        if e == a {
+            println!("      {}", e.unwrap());
+            continue;
+        }
+        if let Some(e) = e {
+            println!("    - {}", e);
+        }
+        if let Some(a) = a {
+            println!("    + {}", a);
+        }
+    }
+}
+
+// Run each of the given parts listed in the day's manifest and report each result
+pub fn verify_day(
+    year: u32,
+    day: u32,
+    dir: &Path,
+    parts: &[u32],
+    run: Runner,
+) -> Result<Summary, String> {
+    let mut summary = Summary::default();
+    let manifest = match load_manifest(dir)? {
+        Some(manifest) => manifest,
+        None => {
+            println!("{} day {}: no {}", year, day, MANIFEST);
+            return Ok(summary);
+        }
+    };
+
+    for (file, expected) in &manifest {
+        let path = dir.join(file);
+        let contents = input::read_file(&path)?;
+        let overrides = params::load(dir, file)?;
+        for &part in parts {
+            let expected = match expected.part(part) {
+                Some(expected) => expected,
+                None => continue,
+            };
+            let label = format!("{} day {} part {} ({})", year, day, part, file);
+            let actual = match run(&contents, part, &overrides) {
+                Ok(actual) => actual,
+                Err(err) => {
+                    println!("FAIL {}", label);
+                    for line in err.with_file(&path).to_string().lines() {
+                        println!("    {}", line);
+                    }
+                    summary.failed += 1;
+                    continue;
+                }
+            };
+            if actual.trim_end() == expected.trim_end() {
+                println!("PASS {}", label);
+                summary.passed += 1;
+            } else {
+                println!("FAIL {}", label);
+                print_diff(expected.trim_end(), actual.trim_end());
+                summary.failed += 1;
+            }
+        }
+    }
+
+    Ok(summary)
+}
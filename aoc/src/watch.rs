@@ -0,0 +1,144 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Rebuilding the runner and verifying a day again whenever its code or inputs change.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_common::info;
+
+use crate::bench::HISTORY;
+
+/// What to watch and how to check it.
+pub struct Options {
+    pub year: u32,
+    pub day: u32,
+    pub part: Option<u32>,
+    /// How often to look for changes.
+    pub interval: Duration,
+}
+
+// Modification times of the watched files, to compare between polls
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+// Add every file below a directory to the snapshot, skipping build output, hidden
+// directories and the benchmark history, which the runner writes itself
+fn scan(dir: &Path, snapshot: &mut Snapshot) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if path.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                scan(&path, snapshot);
+            }
+        } else if name != HISTORY {
+            if let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                snapshot.insert(path, modified);
+            }
+        }
+    }
+}
+
+// The day's directory, the year's solution table and the shared library
+fn snapshot(root: &Path, year_dir: &Path, day_dir: &Path) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    scan(day_dir, &mut snapshot);
+    scan(&root.join("common").join("src"), &mut snapshot);
+    let lib = year_dir.join("lib.rs");
+    if let Ok(modified) = fs::metadata(&lib).and_then(|metadata| metadata.modified()) {
+        snapshot.insert(lib, modified);
+    }
+    snapshot
+}
+
+// Rebuild the runner with the profile it was built with, returning whether that worked
+fn rebuild(root: &Path) -> Result<bool, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(&cargo);
+    command
+        .args(["build", "--package", "aoc"])
+        .current_dir(root);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let status = command
+        .status()
+        .map_err(|err| format!("Could not run {}: {}", cargo, err))?;
+    Ok(status.success())
+}
+
+// Verify the day with the freshly built runner at `exe`
+fn verify(exe: &Path, options: &Options) -> Result<(), String> {
+    let mut command = Command::new(exe);
+    command.args([
+        "verify",
+        "--year",
+        &options.year.to_string(),
+        "--day",
+        &options.day.to_string(),
+    ]);
+    if let Some(part) = options.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    // A failing check is reported by verify itself, so only a failure to start matters
+    command
+        .status()
+        .map(drop)
+        .map_err(|err| format!("Could not run {}: {}", exe.display(), err))
+}
+
+// Rebuild and verify the day, then again every time one of its files changes
+pub fn watch(
+    root: &Path,
+    year_dir: &Path,
+    day_dir: &Path,
+    options: &Options,
+) -> Result<(), String> {
+    // Find the runner before rebuilding it. Once cargo replaces the binary, the
+    // running process's own path names the old, deleted file.
+    let exe = env::current_exe().map_err(|err| format!("Could not find the runner: {}", err))?;
+    let mut last = Snapshot::new();
+    loop {
+        let current = snapshot(root, year_dir, day_dir);
+        if current != last {
+            if !last.is_empty() {
+                // Files that are new, modified or removed since the last poll
+                let changed = current
+                    .iter()
+                    .filter(|(path, modified)| last.get(*path) != Some(modified))
+                    .chain(last.iter().filter(|(path, _)| !current.contains_key(*path)))
+                    .map(|(path, _)| {
+                        path.strip_prefix(root)
+                            .unwrap_or(path)
+                            .display()
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>();
+                info!("Changed: {}", changed.join(", "));
+            }
+            last = current;
+
+            println!("--- {} day {}: rebuilding ---", options.year, options.day);
+            if rebuild(root)? {
+                verify(&exe, options)?;
+            } else {
+                println!("Build failed");
+            }
+            println!("--- waiting for changes ---");
+        }
+        thread::sleep(options.interval);
+    }
+}
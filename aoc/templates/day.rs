@@ -0,0 +1,27 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input = Vec<String>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Vec<String>, _: &()) -> impl Display {
+        lines.len()
+    }
+
+    fn part2(lines: &Vec<String>, _: &()) -> impl Display {
+        lines.len()
+    }
+}
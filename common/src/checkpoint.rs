@@ -0,0 +1,165 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Checkpoints of long-running searches, so an interrupted run can pick up
+//! where it stopped.
+//!
+//! The runner installs a [`Store`] on the thread that solves a part. Searches
+//! save their state, such as the current row or the pending frontier, whenever
+//! [`due`] says so and when they are cancelled, and start from [`resume`] if it
+//! gives them a saved state.
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{debug, info, warn};
+
+/// How often a search saves its state.
+pub const INTERVAL: Duration = Duration::from_secs(10);
+
+/// Where a part's checkpoint is kept, and whether to resume from it.
+#[derive(Clone)]
+pub struct Store {
+    pub path: PathBuf,
+    /// Identifies the input and parameters the state belongs to, so a checkpoint
+    /// is never resumed against a different puzzle.
+    pub fingerprint: String,
+    /// Start from the saved state rather than from scratch.
+    pub resume: bool,
+}
+
+impl Store {
+    /// Remove the checkpoint, once the part has been solved to the end.
+    pub fn clear(&self) {
+        // There is nothing to remove if the search never saved
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// The file a checkpoint is written to
+#[derive(Serialize)]
+struct Saved<'a, T> {
+    fingerprint: &'a str,
+    state: &'a T,
+}
+
+// The file a checkpoint is read back from
+#[derive(Deserialize)]
+struct Loaded<T> {
+    fingerprint: String,
+    state: T,
+}
+
+// The store on this thread and when its state was last saved
+struct Current {
+    store: Store,
+    saved: Instant,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Current>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with `store` keeping this thread's checkpoints.
+pub fn with_store<T>(store: &Store, f: impl FnOnce() -> T) -> T {
+    let current = Current {
+        store: store.clone(),
+        saved: Instant::now(),
+    };
+    let previous = CURRENT.with(|cell| cell.replace(Some(current)));
+    let result = f();
+    CURRENT.with(|cell| *cell.borrow_mut() = previous);
+    result
+}
+
+/// The saved state to continue the search from, if the runner asked to resume
+/// and there is a checkpoint for this input.
+pub fn resume<T: DeserializeOwned>() -> Option<T> {
+    let store = CURRENT.with(|cell| cell.borrow().as_ref().map(|current| current.store.clone()))?;
+    if !store.resume {
+        return None;
+    }
+    let contents = match fs::read_to_string(&store.path) {
+        Ok(contents) => contents,
+        Err(_) => {
+            warn!("No checkpoint at {}, starting over", store.path.display());
+            return None;
+        }
+    };
+    match serde_json::from_str::<Loaded<T>>(&contents) {
+        Ok(loaded) if loaded.fingerprint == store.fingerprint => {
+            info!("Resuming from {}", store.path.display());
+            Some(loaded.state)
+        }
+        Ok(_) => {
+            warn!(
+                "The checkpoint at {} is for a different input or parameters, starting over",
+                store.path.display()
+            );
+            None
+        }
+        Err(err) => {
+            warn!(
+                "Could not read the checkpoint at {}: {}, starting over",
+                store.path.display(),
+                err
+            );
+            None
+        }
+    }
+}
+
+/// Whether it has been [`INTERVAL`] since the state was last saved. Always
+/// false outside [`with_store`].
+pub fn due() -> bool {
+    CURRENT.with(|cell| {
+        cell.borrow()
+            .as_ref()
+            .is_some_and(|current| current.saved.elapsed() >= INTERVAL)
+    })
+}
+
+/// Save the state of the search. Does nothing outside [`with_store`], and only
+/// warns if the checkpoint can't be written, since the search can go on without it.
+pub fn save<T: Serialize>(state: &T) {
+    CURRENT.with(|cell| {
+        let mut cell = cell.borrow_mut();
+        let current = match cell.as_mut() {
+            Some(current) => current,
+            None => return,
+        };
+        let saved = Saved {
+            fingerprint: &current.store.fingerprint,
+            state,
+        };
+        if let Err(err) = write(&current.store.path, &saved) {
+            warn!(
+                "Could not save the checkpoint to {}: {}",
+                current.store.path.display(),
+                err
+            );
+        }
+        current.saved = Instant::now();
+    });
+}
+
+// Write the checkpoint beside its path and move it into place, so an interruption
+// mid-write leaves the previous checkpoint intact
+fn write<T: Serialize>(path: &Path, saved: &Saved<T>) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+    }
+    let contents = serde_json::to_string(saved).map_err(|err| err.to_string())?;
+    let partial = path.with_extension("partial");
+    fs::write(&partial, contents).map_err(|err| err.to_string())?;
+    fs::rename(&partial, path).map_err(|err| err.to_string())?;
+    debug!("Saved a checkpoint to {}", path.display());
+    Ok(())
+}
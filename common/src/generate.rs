@@ -0,0 +1,24 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Random puzzle inputs, for stress-testing solutions beyond the one real input
+//! and the examples.
+//!
+//! A day registers a [`Generator`] that writes inputs in its puzzle's format,
+//! which that day's parser accepts.
+
+use rand::SeedableRng;
+
+/// The random number generator handed to generators, seeded so the same input
+/// can be written again.
+pub type Rng = rand::rngs::StdRng;
+
+/// Writes a random input of about `size` lines, items or rows, depending on the day.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// A random number generator seeded with `seed`.
+pub fn rng(seed: u64) -> Rng {
+    Rng::seed_from_u64(seed)
+}
@@ -0,0 +1,396 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Shortest paths through graphs, whether the edges are worked out as the
+//! search goes, like the moves on a map, or listed up front.
+//!
+//! Every search starts from one or more nodes and stops at the first node that
+//! `goal` accepts, or once it has reached everything it can when `goal` never
+//! does. The [`Search`] it returns knows how far each node it settled is from
+//! the nearest start and the way back to it.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A graph of nodes joined by one-way edges, each with a cost.
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+
+    /// The nodes one edge away from `node`, each with the cost of getting there.
+    fn neighbors(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+/// A graph whose edges come from a function of the node, so they never need
+/// to be stored. Made by [`from_fn`].
+pub struct FromFn<N, F> {
+    neighbors: F,
+    node: PhantomData<N>,
+}
+
+/// A graph where `neighbors` gives the nodes one edge away from a node, each
+/// with the cost of getting there.
+pub fn from_fn<N, F, I>(neighbors: F) -> FromFn<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    FromFn {
+        neighbors,
+        node: PhantomData,
+    }
+}
+
+impl<N, F, I> Graph for FromFn<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    type Node = N;
+
+    fn neighbors(&self, node: N) -> impl Iterator<Item = (N, u64)> {
+        (self.neighbors)(node).into_iter()
+    }
+}
+
+/// A graph with its edges listed out, between nodes numbered from 0.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdjacencyList {
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl AdjacencyList {
+    /// A graph of `nodes` nodes with no edges yet.
+    pub fn new(nodes: usize) -> AdjacencyList {
+        AdjacencyList {
+            edges: vec![Vec::new(); nodes],
+        }
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Whether there are no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Add an edge from `from` to `to` costing `cost`. Edges only go one way, so
+    /// a two-way tunnel is two edges.
+    pub fn add_edge(&mut self, from: usize, to: usize, cost: u64) {
+        self.edges[from].push((to, cost));
+    }
+}
+
+impl Graph for AdjacencyList {
+    type Node = usize;
+
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, u64)> {
+        self.edges[node].iter().copied()
+    }
+}
+
+/// What a search found: how far each node it settled is from the nearest
+/// start, and the node before it on the way there.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    distances: HashMap<N, u64>,
+    predecessors: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Copy + Eq + Hash> Search<N> {
+    /// The goal the search stopped at, or `None` if it never reached one.
+    pub fn goal(&self) -> Option<N> {
+        self.goal
+    }
+
+    /// The cost of the cheapest way to `node` from any start, if it was reached.
+    pub fn distance(&self, node: N) -> Option<u64> {
+        self.distances.get(&node).copied()
+    }
+
+    /// Every node that was reached, with its distance, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (N, u64)> + '_ {
+        self.distances
+            .iter()
+            .map(|(&node, &distance)| (node, distance))
+    }
+
+    /// The cheapest way to `node`, from the start it came from to `node` itself,
+    /// if it was reached.
+    pub fn path(&self, node: N) -> Option<Vec<N>> {
+        self.distances.get(&node)?;
+        let mut path = vec![node];
+        while let Some(&previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Search outwards from every node in `starts` one edge at a time, ignoring
+/// the edges' costs, so each distance is a number of edges.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut goal: impl FnMut(G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut search = Search {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if goal(node) {
+            search.goal = Some(node);
+            break;
+        }
+        let distance = search.distances[&node] + 1;
+        for (next, _) in graph.neighbors(node) {
+            // The first time a node is reached is the closest it can be
+            if let Entry::Vacant(entry) = search.distances.entry(next) {
+                entry.insert(distance);
+                search.predecessors.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Search outwards from every node in `starts` in order of the total cost of
+/// the edges to each node.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    goal: impl FnMut(G::Node) -> bool,
+) -> Search<G::Node> {
+    astar(graph, starts, goal, |_| 0)
+}
+
+/// Search from every node in `starts` toward a goal, trying first the nodes that
+/// look cheapest by their cost so far plus `heuristic`'s guess at the cost left.
+/// The guess must never be more than the real cost, nor fall by more than an
+/// edge's cost along it, or a longer way may be found.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut goal: impl FnMut(G::Node) -> bool,
+    mut heuristic: impl FnMut(G::Node) -> u64,
+) -> Search<G::Node> {
+    // The cheapest cost found so far to each node, which only becomes its
+    // distance once the node leaves the queue
+    let mut costs = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut distances = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        costs.insert(start, 0);
+        queue.push(Queued {
+            estimate: heuristic(start),
+            cost: 0,
+            node: start,
+        });
+    }
+
+    let mut found = None;
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // Skip the stale entries left behind when a cheaper way was found
+        if distances.contains_key(&node) || costs[&node] < cost {
+            continue;
+        }
+        distances.insert(node, cost);
+        if goal(node) {
+            found = Some(node);
+            break;
+        }
+        for (next, edge) in graph.neighbors(node) {
+            let cost = cost + edge;
+            if costs.get(&next).is_none_or(|&known| cost < known) {
+                costs.insert(next, cost);
+                predecessors.insert(next, node);
+                queue.push(Queued {
+                    estimate: cost + heuristic(next),
+                    cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    // Only the nodes that were settled have a way back that is known to be cheapest
+    predecessors.retain(|node, _| distances.contains_key(node));
+    Search {
+        distances,
+        predecessors,
+        goal: found,
+    }
+}
+
+// A node waiting in the queue, ordered so the lowest estimate comes out first
+struct Queued<N> {
+    estimate: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Queued<N>) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Queued<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Queued<N>) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// The cost of the cheapest way between every pair of the `nodes` nodes of
+/// `graph`, indexed `[from][to]`, or `None` where there is no way at all.
+pub fn floyd_warshall<G: Graph<Node = usize>>(graph: &G, nodes: usize) -> Vec<Vec<Option<u64>>> {
+    let mut distances = vec![vec![None; nodes]; nodes];
+    for (from, row) in distances.iter_mut().enumerate() {
+        row[from] = Some(0);
+        for (to, cost) in graph.neighbors(from) {
+            row[to] = Some(row[to].map_or(cost, |known: u64| known.min(cost)));
+        }
+    }
+
+    // Allow the ways between each pair to go through one more node at a time
+    for via in 0..nodes {
+        let onward = distances[via].clone();
+        for row in &mut distances {
+            let Some(first) = row[via] else {
+                continue;
+            };
+            for (known, &second) in row.iter_mut().zip(&onward) {
+                if let Some(second) = second {
+                    let cost = first + second;
+                    if known.is_none_or(|known| cost < known) {
+                        *known = Some(cost);
+                    }
+                }
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 in steps of 1, a costly shortcut 0 -> 3, and 4 on its own
+    fn line() -> AdjacencyList {
+        let mut graph = AdjacencyList::new(5);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, 1);
+        graph.add_edge(2, 3, 1);
+        graph.add_edge(0, 3, 10);
+        graph
+    }
+
+    // Every node is as far as its nearest start, and the goal found is the one
+    // nearest any start
+    #[test]
+    fn bfs_from_many_starts() {
+        let graph = line();
+        let search = bfs(&graph, [0, 2], |_| false);
+        assert_eq!(search.distance(0), Some(0));
+        assert_eq!(search.distance(1), Some(1));
+        assert_eq!(search.distance(2), Some(0));
+        assert_eq!(search.distance(3), Some(1));
+        assert_eq!(search.distance(4), None);
+        assert_eq!(search.path(1), Some(vec![0, 1]));
+
+        let search = bfs(&graph, [1, 2], |node| node == 3);
+        assert_eq!(search.goal(), Some(3));
+        assert_eq!(search.path(3), Some(vec![2, 3]));
+    }
+
+    // BFS counts edges, whatever they cost
+    #[test]
+    fn bfs_ignores_costs() {
+        let search = bfs(&line(), [0], |node| node == 3);
+        assert_eq!(search.distance(3), Some(1));
+        assert_eq!(search.path(3), Some(vec![0, 3]));
+    }
+
+    // The shortcut is queued first and left stale once the cheaper way is
+    // found, so it must not settle the node or set the way back
+    #[test]
+    fn stale_entries() {
+        let graph = line();
+        let search = dijkstra(&graph, [0], |_| false);
+        assert_eq!(search.distance(3), Some(3));
+        assert_eq!(search.path(3), Some(vec![0, 1, 2, 3]));
+
+        let search = astar(&graph, [0], |node| node == 3, |node| 3 - node.min(3) as u64);
+        assert_eq!(search.goal(), Some(3));
+        assert_eq!(search.distance(3), Some(3));
+        assert_eq!(search.path(3), Some(vec![0, 1, 2, 3]));
+    }
+
+    // Paths run from the start the node was reached from to the node itself
+    #[test]
+    fn path_from_start() {
+        let graph = from_fn(|n: i32| {
+            [(n + 1, 1), (n * 2, 1)]
+                .into_iter()
+                .filter(|&(n, _)| n <= 20)
+        });
+        let search = bfs(&graph, [1], |node| node == 20);
+        assert_eq!(search.path(20), Some(vec![1, 2, 4, 5, 10, 20]));
+        assert_eq!(search.path(1), Some(vec![1]));
+        assert_eq!(search.path(-1), None);
+        assert_eq!(search.goal(), Some(20));
+    }
+
+    // A goal that can't be reached leaves everything reachable searched
+    #[test]
+    fn unreachable_goal() {
+        let search = dijkstra(&line(), [1], |node| node == 4);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.distances().count(), 3);
+        assert_eq!(search.path(4), None);
+    }
+
+    #[test]
+    fn floyd_warshall_pairs() {
+        let graph = line();
+        let distances = floyd_warshall(&graph, graph.len());
+        assert_eq!(distances[0], [Some(0), Some(1), Some(2), Some(3), None]);
+        assert_eq!(distances[2][3], Some(1));
+        // Edges only go one way, and nothing reaches the node on its own
+        assert_eq!(distances[3][0], None);
+        assert_eq!(distances[1][4], None);
+        assert_eq!(distances[4][0], None);
+        assert_eq!(distances[4][4], Some(0));
+    }
+}
@@ -0,0 +1,330 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! A dense, rectangular grid, for puzzles drawn as a map of characters.
+//!
+//! Cells are addressed by `(row, column)`, with row 0 at the top and column 0
+//! on the left, the same way the puzzle input reads.
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// Steps to the four neighbors of a cell, as `(row, column)` offsets: up, left,
+/// right and down.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Steps to the eight neighbors of a cell, including the diagonals, in reading order.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid `height` rows tall and `width` columns wide, with every cell set to `value`.
+    pub fn new(height: usize, width: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse a grid with one character per cell and one line per row, reporting
+    /// that `expected` was wanted at any character `cell` turns down. Blank lines
+    /// and whitespace around each row are skipped, and every row must be as wide
+    /// as the first.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let mut columns = 0;
+            for (i, c) in line.char_indices() {
+                if width.is_some_and(|width| columns == width) {
+                    return Err(ParseError::at(input, &line[i..], "the end of the row"));
+                }
+                cells.push(cell(c).ok_or_else(|| ParseError::at(input, &line[i..], expected))?);
+                columns += 1;
+            }
+            if let Some(width) = width {
+                if columns < width {
+                    let expected = match width - columns {
+                        1 => "another cell".to_string(),
+                        missing => format!("{} more cells", missing),
+                    };
+                    return Err(ParseError::after(input, line, expected));
+                }
+            }
+            width = Some(columns);
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(row, column)` is inside the grid.
+    pub fn contains(&self, (row, column): (usize, usize)) -> bool {
+        row < self.height && column < self.width
+    }
+
+    /// The cell at `(row, column)`, or `None` outside the grid.
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    /// The cell at `(row, column)` to change, or `None` outside the grid.
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        Some(&mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// The position one `step` away from `position`, if it is inside the grid.
+    pub fn step(
+        &self,
+        (row, column): (usize, usize),
+        step: (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let position = (
+            row.checked_add_signed(step.0)?,
+            column.checked_add_signed(step.1)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// The positions of the up to four cells sharing an edge with `position`.
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&step| self.step(position, step))
+    }
+
+    /// The positions of the up to eight cells sharing an edge or a corner with `position`.
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |&step| self.step(position, step))
+    }
+
+    /// The cells from `position` in the direction of `step` to the edge of the
+    /// grid, nearest first and not including `position` itself.
+    pub fn ray(
+        &self,
+        position: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = &T> + '_ {
+        std::iter::successors(self.step(position, step), move |&position| {
+            self.step(position, step)
+        })
+        .map(|position| &self[position])
+    }
+
+    /// One row, from left to right.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// One column, from top to bottom.
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.height).map(move |row| &self[(row, column)])
+    }
+
+    /// Every row, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i / self.width, i % self.width), cell))
+    }
+
+    /// The position of the first cell, in reading order, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters, one line per row.
+    pub fn from_chars(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, "a character", Some)
+    }
+}
+
+impl Grid<u32> {
+    /// Parse a grid of decimal digits, one line per row.
+    pub fn from_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", position, height, width))
+    }
+}
+
+/// One line per row with the cells side by side. Formatting options apply to
+/// each cell, so `{:>3}` lines up a grid of numbers.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rows() {
+        let grid = Grid::from_digits("123\n456\n").unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.row(0), [1, 2, 3]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<u32>>(), [3, 6]);
+    }
+
+    // A row that is too long is rejected at its first extra cell, and one that
+    // is too short at its end
+    #[test]
+    fn ragged_rows() {
+        let input = "abc\nabcd\n";
+        let expected = ParseError::at(input, &input[7..], "the end of the row");
+        assert_eq!(Grid::from_chars(input), Err(expected));
+        let input = "abc\na\n";
+        let expected = ParseError::after(input, &input[4..5], "2 more cells");
+        assert_eq!(Grid::from_chars(input), Err(expected));
+        let input = "abc\nab\n";
+        let expected = ParseError::after(input, &input[4..6], "another cell");
+        assert_eq!(Grid::from_chars(input), Err(expected));
+    }
+
+    #[test]
+    fn bad_cell() {
+        let input = "12\n3x\n";
+        let expected = ParseError::at(input, &input[4..], "a digit");
+        assert_eq!(Grid::from_digits(input), Err(expected));
+    }
+
+    // Corners and edges only have the neighbors inside the grid
+    #[test]
+    fn edge_neighbors() {
+        let grid = Grid::new(3, 4, '.');
+        let neighbors = |position| grid.neighbors4(position).collect::<Vec<(usize, usize)>>();
+        assert_eq!(neighbors((0, 0)), [(0, 1), (1, 0)]);
+        assert_eq!(neighbors((2, 3)), [(1, 3), (2, 2)]);
+        assert_eq!(neighbors((0, 2)), [(0, 1), (0, 3), (1, 2)]);
+        assert_eq!(neighbors((1, 1)), [(0, 1), (1, 0), (1, 2), (2, 1)]);
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn step_off_the_edge() {
+        let grid = Grid::new(2, 2, 0);
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((1, 1), (0, 1)), None);
+        assert_eq!(grid.step((0, 1), (1, -1)), Some((1, 0)));
+        assert_eq!(grid.get((2, 0)), None);
+    }
+
+    #[test]
+    fn ray_to_the_edge() {
+        let grid = Grid::from_digits("123\n456\n789").unwrap();
+        assert_eq!(grid.ray((1, 1), (0, 1)).collect::<Vec<&u32>>(), [&6]);
+        assert_eq!(grid.ray((2, 2), (-1, -1)).collect::<Vec<&u32>>(), [&5, &1]);
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    }
+
+    // The first match in reading order, row by row
+    #[test]
+    fn position() {
+        let grid = Grid::from_chars("..S\nS..").unwrap();
+        assert_eq!(grid.position(|&c| c == 'S'), Some((0, 2)));
+        assert_eq!(grid.position(|&c| c == 'E'), None);
+    }
+
+    #[test]
+    fn display() {
+        let mut grid = Grid::new(2, 3, '.');
+        grid[(1, 2)] = '#';
+        assert_eq!(grid.to_string(), "...\n..#");
+        let grid = Grid::from_digits("12\n34").unwrap();
+        assert_eq!(format!("{:>2}", grid), " 1 2\n 3 4");
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Counting heap allocations, so the runner can report what each phase of a
+//! solution allocates.
+//!
+//! [`Counting`] wraps the system allocator and is installed by the runner as its
+//! global allocator. It only counts once [`enable`] has been called, and keeps
+//! its counts per thread, so parts solved side by side don't see each other's
+//! allocations.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+// Counts for the current thread. Memory freed on another thread than the one that
+// allocated it is subtracted from the thread that frees it, so `LIVE` can go negative.
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting what each thread allocates while enabled.
+pub struct Counting;
+
+// Record an allocation of `size` bytes, or a reallocation from `freed` bytes
+fn record_alloc(size: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // The counts are unavailable while the thread is being torn down
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+    let _ = LIVE.try_with(|live| {
+        live.set(live.get() + size as i64 - freed as i64);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+    });
+}
+
+// Record that `size` bytes were freed
+fn record_dealloc(size: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_alloc(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Start counting allocations. Has no effect unless [`Counting`] is the global
+/// allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether allocations are being counted.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// What one phase allocated on its thread.
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct Allocs {
+    /// Number of allocations, counting each reallocation as one.
+    pub allocations: u64,
+    /// Total bytes asked for, including reallocations.
+    pub bytes: u64,
+    /// The most heap the phase held at once, on top of what was already
+    /// allocated when it started.
+    pub peak_bytes: u64,
+}
+
+impl Allocs {
+    /// The counts of two phases run one after the other. The peak is the larger
+    /// of the two, since each is measured from the start of its own phase.
+    pub fn then(self, next: Allocs) -> Allocs {
+        Allocs {
+            allocations: self.allocations + next.allocations,
+            bytes: self.bytes + next.bytes,
+            peak_bytes: self.peak_bytes.max(next.peak_bytes),
+        }
+    }
+}
+
+/// Run `f`, returning what it allocated on this thread if counting is enabled.
+/// Measurements don't nest, since each one resets the thread's peak.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    if !enabled() {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.with(Cell::get);
+    let bytes = BYTES.with(Cell::get);
+    let live = LIVE.with(Cell::get);
+    PEAK.with(|peak| peak.set(live));
+
+    let result = f();
+
+    let allocs = Allocs {
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+        bytes: BYTES.with(Cell::get) - bytes,
+        peak_bytes: (PEAK.with(Cell::get) - live).max(0) as u64,
+    };
+    (result, Some(allocs))
+}
@@ -0,0 +1,293 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Sets of integers stored as the ranges they cover, for puzzles about
+//! sections, spans of a row and other runs of numbers too long to list.
+
+use std::fmt;
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// The integer types an interval's ends can be.
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A set of integers, kept as sorted, inclusive ranges that neither overlap
+/// nor touch, so each run of numbers in the set is a single range.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+// Whether a range ending at `end` stops short of one starting at `start`, with
+// at least one number between them
+fn apart<T: Integer>(end: T, start: T) -> bool {
+    end < start && end + T::ONE < start
+}
+
+impl<T: Integer> IntervalSet<T> {
+    /// An empty set.
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Whether the set has no numbers in it.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many numbers are in the set.
+    pub fn total_len(&self) -> T {
+        self.ranges.iter().fold(T::ZERO, |total, &(start, end)| {
+            total + (end - start) + T::ONE
+        })
+    }
+
+    /// The runs of numbers in the set, from lowest to highest.
+    pub fn ranges(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Whether `value` is in the set.
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Add every number in `range`, merging it with the ranges it overlaps or
+    /// touches. An empty range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // The ranges from `first` up to `last` overlap or touch the new one
+        let first = self
+            .ranges
+            .partition_point(|&(_, other_end)| apart(other_end, start));
+        let last = self
+            .ranges
+            .partition_point(|&(other_start, _)| !apart(end, other_start));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Every number in either set.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range);
+        }
+        union
+    }
+
+    /// Every number in both sets.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Move past whichever range ends first
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Every number in this set but not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for &(start, end) in &self.ranges {
+            // Skip the ranges of `other` that end before this one starts
+            while j < other.ranges.len() && other.ranges[j].1 < start {
+                j += 1;
+            }
+            // Cut each overlapping range of `other` out of this one
+            let mut rest = Some(start);
+            let mut k = j;
+            while let Some(start) = rest {
+                match other.ranges.get(k) {
+                    Some(&(cut_start, cut_end)) if cut_start <= end => {
+                        if cut_start > start {
+                            ranges.push((start, cut_start - T::ONE));
+                        }
+                        rest = (cut_end < end).then(|| cut_end + T::ONE);
+                        // A cut that runs past this range may cover the next one too
+                        if cut_end <= end {
+                            k += 1;
+                        }
+                    }
+                    _ => {
+                        ranges.push((start, end));
+                        rest = None;
+                    }
+                }
+            }
+            j = k;
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Whether every number in `other` is also in this set.
+    pub fn is_superset(&self, other: &IntervalSet<T>) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    /// Whether the sets have any number in common.
+    pub fn overlaps(&self, other: &IntervalSet<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The runs of numbers in `within` that are missing from the set.
+    pub fn gaps(&self, within: RangeInclusive<T>) -> IntervalSet<T> {
+        IntervalSet::from(within).difference(self)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+/// The ranges as a set, e.g. `{1..=3, 7..=9}`.
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set()
+            .entries(self.ranges.iter().map(|(start, end)| start..=end))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    // Ranges that touch end to end merge as readily as ones that overlap
+    #[test]
+    fn insert_adjacent() {
+        assert_eq!(set(&[1..=2, 3..=4]), set(&[1..=4]));
+        assert_eq!(set(&[3..=4, 1..=2]), set(&[1..=4]));
+        assert_eq!(set(&[1..=2, 4..=5]).ranges().count(), 2);
+        assert_eq!(set(&[1..=2, 4..=5, 3..=3]), set(&[1..=5]));
+    }
+
+    #[test]
+    fn insert_overlapping() {
+        assert_eq!(set(&[1..=5, 3..=8]), set(&[1..=8]));
+        assert_eq!(set(&[1..=2, 5..=6, 9..=10, 0..=7]), set(&[0..=7, 9..=10]));
+        assert_eq!(set(&[RangeInclusive::new(5, 4)]), IntervalSet::new());
+    }
+
+    // A range inside another adds nothing, and one around others swallows them
+    #[test]
+    fn insert_contained() {
+        assert_eq!(set(&[1..=10, 3..=5]), set(&[1..=10]));
+        assert_eq!(set(&[3..=5, 7..=8, 1..=10]), set(&[1..=10]));
+        assert!(set(&[1..=10]).is_superset(&set(&[3..=5, 7..=8])));
+        assert!(!set(&[1..=10]).is_superset(&set(&[3..=5, 10..=11])));
+    }
+
+    #[test]
+    fn contains() {
+        let covered = set(&[1..=3, 7..=9]);
+        assert!(covered.contains(1) && covered.contains(3) && covered.contains(8));
+        assert!(!covered.contains(0) && !covered.contains(5) && !covered.contains(10));
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let (a, b) = (set(&[1..=3, 8..=10]), set(&[2..=5, 9..=9, 12..=12]));
+        assert_eq!(a.union(&b), set(&[1..=5, 8..=10, 12..=12]));
+        assert_eq!(a.intersection(&b), set(&[2..=3, 9..=9]));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&set(&[4..=7])));
+    }
+
+    #[test]
+    fn difference() {
+        let a = set(&[1..=10, 20..=30]);
+        assert_eq!(
+            a.difference(&set(&[3..=4, 8..=22])),
+            set(&[1..=2, 5..=7, 23..=30])
+        );
+        assert_eq!(
+            a.difference(&set(&[0..=1, 30..=31])),
+            set(&[2..=10, 20..=29])
+        );
+        assert_eq!(a.difference(&set(&[0..=40])), IntervalSet::new());
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+    }
+
+    // Gaps run right up to the ends of the window, and there are none when the
+    // set covers it
+    #[test]
+    fn gaps_at_the_ends() {
+        let covered = set(&[3..=4, 7..=8]);
+        assert_eq!(covered.gaps(0..=10), set(&[0..=2, 5..=6, 9..=10]));
+        assert_eq!(covered.gaps(3..=8), set(&[5..=6]));
+        assert_eq!(covered.gaps(4..=4), IntervalSet::new());
+        assert_eq!(covered.gaps(-2..=-1), set(&[-2..=-1]));
+    }
+
+    #[test]
+    fn total_len() {
+        assert_eq!(set(&[1..=3, 7..=9, 11..=11]).total_len(), 7);
+        assert_eq!(set(&[-5..=-1, 0..=4]).total_len(), 10);
+        assert_eq!(IntervalSet::<u8>::new().total_len(), 0);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", set(&[1..=3, 7..=9])), "{1..=3, 7..=9}");
+    }
+}
@@ -0,0 +1,312 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Shared pieces used by every year's solutions and by the runner.
+
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub mod cancel;
+pub mod checkpoint;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod heap;
+pub mod interval;
+pub mod log;
+pub mod params;
+pub mod point;
+pub mod sparse_grid;
+
+pub use cancel::{cancelled, CancelToken};
+pub use generate::{Generator, Rng};
+pub use graph::Graph;
+pub use grid::Grid;
+pub use heap::Allocs;
+pub use interval::IntervalSet;
+pub use params::{Overrides, Params};
+pub use point::{Direction, Point2, Vec2};
+pub use sparse_grid::{Bounds, SparseGrid};
+
+/// An error in the puzzle input, located by line and column so it can be
+/// shown with a caret under the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The input file, once the caller knows it.
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// What the parser expected to find, e.g. "an integer" or "`, y=`".
+    pub expected: String,
+    /// The text of the line the error is on.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error at the start of `at`, which must be a slice of `input`.
+    pub fn at(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at_offset(input, offset_in(input, at), expected.into())
+    }
+
+    /// An error just past the end of `at`, which must be a slice of `input`.
+    /// Used when something is missing rather than malformed.
+    pub fn after(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at_offset(input, offset_in(input, at) + at.len(), expected.into())
+    }
+
+    fn at_offset(input: &str, offset: usize, expected: String) -> ParseError {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        ParseError {
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Attach the file the input was read from.
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> ParseError {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+// The byte offset of `at` within `input`; slices from elsewhere point at the end
+fn offset_in(input: &str, at: &str) -> usize {
+    let offset = (at.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    if offset <= input.len() {
+        offset
+    } else {
+        input.len()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = match &self.file {
+            Some(file) => file.display().to_string(),
+            None => "<input>".to_string(),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "error: expected {}", self.expected)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a solution could not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The puzzle input is malformed.
+    Parse(ParseError),
+    /// A parameter override is unknown, has a malformed value, or doesn't suit
+    /// the input.
+    Params(String),
+}
+
+impl Error {
+    /// Record which input file a parse error is in.
+    pub fn with_file(self, file: impl Into<PathBuf>) -> Error {
+        match self {
+            Error::Parse(err) => Error::Parse(err.with_file(file)),
+            err => err,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        Error::Parse(err)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Params(err) => write!(f, "error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parse `field`, a slice of `input`, reporting that `expected` was wanted there if it fails.
+pub fn parse_field<T: FromStr>(input: &str, field: &str, expected: &str) -> Result<T, ParseError> {
+    field
+        .parse()
+        .map_err(|_| ParseError::at(input, field, expected))
+}
+
+/// A single day's puzzle, split into a parse phase and one solve phase per part.
+///
+/// Both parts share the parsed input, so each day only has one parser.
+pub trait Solution {
+    /// The parsed form of the puzzle input.
+    type Input;
+
+    /// Constants of the puzzle that differ between the real input and the
+    /// examples, or `()` if there are none.
+    type Params: Params;
+
+    /// Parse the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solve part 1 of the puzzle.
+    fn part1(input: &Self::Input, params: &Self::Params) -> impl Display;
+
+    /// Solve part 2 of the puzzle.
+    fn part2(input: &Self::Input, params: &Self::Params) -> impl Display;
+
+    /// Check the parameters make sense for the parsed input before solving, e.g.
+    /// that a named starting point exists, describing the problem if they don't.
+    fn check(_input: &Self::Input, _params: &Self::Params) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Alternate implementations of either part, run with `--variant` or `aoc compare`.
+    const VARIANTS: &'static [Variant] = &[];
+
+    /// Writes random inputs for `aoc gen`, if the day has a generator.
+    const GENERATOR: Option<Generator> = None;
+}
+
+/// Type-erased entry point for a variant: the raw input, which it parses itself,
+/// and the parameter overrides.
+pub type VariantRunner = fn(&str, &Overrides) -> Result<String, Error>;
+
+/// An alternate implementation of one part of a day, selected by name.
+pub struct Variant {
+    pub name: &'static str,
+    pub part: u32,
+    pub run: VariantRunner,
+}
+
+/// Parse the input and run a variant's implementation of a part with the day's
+/// parameters, rendering the answer as a string.
+pub fn run_variant<S: Solution, D: Display>(
+    input: &str,
+    overrides: &Overrides,
+    part: impl FnOnce(&S::Input, &S::Params) -> D,
+) -> Result<String, Error> {
+    let params = S::Params::with_overrides(overrides).map_err(Error::Params)?;
+    let parsed = S::parse(input)?;
+    S::check(&parsed, &params).map_err(Error::Params)?;
+    Ok(part(&parsed, &params).to_string())
+}
+
+/// Type-erased entry point for a solution: the raw input, the part to solve and
+/// the parameter overrides.
+pub type Runner = fn(&str, u32, &Overrides) -> Result<String, Error>;
+
+/// Parse the input and solve the given part, rendering the answer as a string.
+pub fn solve<S: Solution>(input: &str, part: u32, overrides: &Overrides) -> Result<String, Error> {
+    let params = S::Params::with_overrides(overrides).map_err(Error::Params)?;
+    let parsed = S::parse(input)?;
+    S::check(&parsed, &params).map_err(Error::Params)?;
+    Ok(match part {
+        1 => S::part1(&parsed, &params).to_string(),
+        2 => S::part2(&parsed, &params).to_string(),
+        _ => panic!("Invalid part: {}", part),
+    })
+}
+
+/// Time spent in each phase of a single run of one part, and what each phase
+/// allocated if allocations are being counted.
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub solve: Duration,
+    pub parse_allocs: Option<Allocs>,
+    pub solve_allocs: Option<Allocs>,
+}
+
+/// Type-erased timing entry point: the raw input, the part to time and the
+/// parameter overrides.
+pub type Timer = fn(&str, u32, &Overrides) -> Result<(String, PhaseTimes), Error>;
+
+/// Parse the input and solve the given part, timing each phase separately.
+/// Rendering the answer as a string is not counted in either phase.
+pub fn time<S: Solution>(
+    input: &str,
+    part: u32,
+    overrides: &Overrides,
+) -> Result<(String, PhaseTimes), Error> {
+    let params = S::Params::with_overrides(overrides).map_err(Error::Params)?;
+    let start = Instant::now();
+    let (parsed, parse_allocs) = heap::measure(|| S::parse(black_box(input)));
+    let parsed = black_box(parsed?);
+    let parse = start.elapsed();
+    S::check(&parsed, &params).map_err(Error::Params)?;
+
+    let start = Instant::now();
+    let (answer, solve, solve_allocs) = match part {
+        1 => {
+            let (answer, allocs) = heap::measure(|| black_box(S::part1(&parsed, &params)));
+            let solve = start.elapsed();
+            (answer.to_string(), solve, allocs)
+        }
+        2 => {
+            let (answer, allocs) = heap::measure(|| black_box(S::part2(&parsed, &params)));
+            let solve = start.elapsed();
+            (answer.to_string(), solve, allocs)
+        }
+        _ => panic!("Invalid part: {}", part),
+    };
+
+    Ok((
+        answer,
+        PhaseTimes {
+            parse,
+            solve,
+            parse_allocs,
+            solve_allocs,
+        },
+    ))
+}
+
+/// A registered solution for one day, with its entry points erased so days
+/// with different input types can share a table.
+pub struct Day {
+    pub day: u32,
+    pub run: Runner,
+    pub time: Timer,
+    pub variants: &'static [Variant],
+    pub generator: Option<Generator>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u32) -> Day {
+        Day {
+            day,
+            run: solve::<S>,
+            time: time::<S>,
+            variants: S::VARIANTS,
+            generator: S::GENERATOR,
+        }
+    }
+
+    /// The variants of the given part.
+    pub fn variants(&self, part: u32) -> impl Iterator<Item = &'static Variant> {
+        self.variants
+            .iter()
+            .filter(move |variant| variant.part == part)
+    }
+}
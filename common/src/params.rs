@@ -0,0 +1,127 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Puzzle parameters, such as the row day 15 scans, that differ between the
+//! real input and the examples.
+//!
+//! Days declare theirs with [`params!`](crate::params!), giving each field its
+//! default for the real puzzle. The runner overrides them by name from the
+//! command line or a day's `params.toml`.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parameter values by name, as text, to apply over a day's defaults.
+pub type Overrides = BTreeMap<String, String>;
+
+/// A day's parameters. The `Default` is the value for the real puzzle.
+pub trait Params: Default {
+    /// The name of every parameter.
+    const NAMES: &'static [&'static str];
+
+    /// Set one parameter from its text.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// The defaults with the overrides applied.
+    fn with_overrides(overrides: &Overrides) -> Result<Self, String> {
+        let mut params = Self::default();
+        for (name, value) in overrides {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Days without parameters.
+impl Params for () {
+    const NAMES: &'static [&'static str] = &[];
+
+    fn set(&mut self, name: &str, _: &str) -> Result<(), String> {
+        Err(unknown(name, Self::NAMES))
+    }
+}
+
+/// Parse the text of a parameter.
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value.parse().map_err(|err| {
+        format!(
+            "Invalid value `{}` for parameter `{}`: {}",
+            value, name, err
+        )
+    })
+}
+
+/// The error for a parameter the day doesn't have.
+pub fn unknown(name: &str, names: &[&str]) -> String {
+    if names.is_empty() {
+        return format!("Unknown parameter `{}` (this day has none)", name);
+    }
+    format!(
+        "Unknown parameter `{}` (expected one of: {})",
+        name,
+        names.join(", ")
+    )
+}
+
+/// Declare a day's parameters as a struct, with the default of each field for
+/// the real puzzle:
+///
+/// ```ignore
+/// aoc_common::params! {
+///     pub struct Params {
+///         /// The row part 1 counts
+///         row: i32 = 2000000,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field:ident: $ty:ty = $default:expr,
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                pub $field: $ty,
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            const NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                match name {
+                    $(
+                        stringify!($field) => {
+                            self.$field = $crate::params::parse_value(name, value)?
+                        }
+                    )*
+                    _ => return Err($crate::params::unknown(name, Self::NAMES)),
+                }
+                Ok(())
+            }
+        }
+    };
+}
@@ -0,0 +1,357 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Points and directions on a 2D grid of integers.
+//!
+//! As with [`SparseGrid`](crate::SparseGrid), y grows downwards, the way a grid
+//! is drawn, so [`Direction::Up`] takes one off y and turning right from `Right`
+//! faces `Down`.
+
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// The signed integer types a point's coordinates can be.
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+
+                fn abs(self) -> $t {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> $t {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A point on the grid, or the offset between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The offset from one [`Point2`] to another, such as a single step.
+pub type Vec2<T> = Point2<T>;
+
+impl<T: Coordinate> Point2<T> {
+    pub const ORIGIN: Point2<T> = Point2::new(T::ZERO, T::ZERO);
+
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    /// Each coordinate made positive.
+    pub fn abs(self) -> Point2<T> {
+        Point2::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Each coordinate as -1, 0 or 1 by its sign, turning an offset into the
+    /// single step, diagonals included, that heads the same way.
+    pub fn signum(self) -> Point2<T> {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Distance to `other` moving only along the axes.
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        let offset = (other - self).abs();
+        offset.x + offset.y
+    }
+
+    /// Distance to `other` when diagonal steps count as one, like a king in chess.
+    pub fn chebyshev(self, other: Point2<T>) -> T {
+        let offset = (other - self).abs();
+        offset.x.max(offset.y)
+    }
+
+    /// One step from this point toward `target`, diagonally unless they share a
+    /// row or column. A point already at the target stays where it is.
+    pub fn step_toward(self, target: Point2<T>) -> Point2<T> {
+        self + (target - self).signum()
+    }
+
+    /// This offset turned a quarter turn anticlockwise, as drawn.
+    pub fn rotate_left(self) -> Point2<T> {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// This offset turned a quarter turn clockwise, as drawn.
+    pub fn rotate_right(self) -> Point2<T> {
+        Point2::new(-self.y, self.x)
+    }
+}
+
+impl<T: Coordinate> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coordinate> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Point2<T> {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+/// Scale an offset, e.g. a direction by a distance.
+impl<T: Coordinate> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, scale: T) -> Point2<T> {
+        Point2::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> (T, T) {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the four ways along the axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// A single step this way.
+    pub fn step<T: Coordinate>(self) -> Vec2<T> {
+        match self {
+            Direction::Up => Point2::new(T::ZERO, -T::ONE),
+            Direction::Down => Point2::new(T::ZERO, T::ONE),
+            Direction::Left => Point2::new(-T::ONE, T::ZERO),
+            Direction::Right => Point2::new(T::ONE, T::ZERO),
+        }
+    }
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// The opposite direction.
+    pub fn reverse(self) -> Direction {
+        self.turn_left().turn_left()
+    }
+}
+
+/// Directions are written as the puzzles write them: `U`, `D`, `L` or `R`.
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Direction, char> {
+        match c {
+            'U' => Ok(Direction::Up),
+            'D' => Ok(Direction::Down),
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(c),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Direction, String> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("`{}` is not one of `U`, `D`, `L` or `R`", s))
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letter = match self {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{}", letter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point2::new(1, -2), Point2::new(-3, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    // Off a shared row or column the step is diagonal, one square each way
+    #[test]
+    fn step_toward_diagonally() {
+        let point = Point2::new(0, 0);
+        assert_eq!(point.step_toward(Point2::new(5, -2)), Point2::new(1, -1));
+        assert_eq!(point.step_toward(Point2::new(-1, 3)), Point2::new(-1, 1));
+        assert_eq!(point.step_toward(Point2::new(0, -4)), Point2::new(0, -1));
+        assert_eq!(point.step_toward(Point2::new(7, 0)), Point2::new(1, 0));
+    }
+
+    // A point on its target stays put, and one touching it steps onto it, so a
+    // rope only pulls its tail along once they are no longer touching
+    #[test]
+    fn step_toward_when_touching() {
+        let tail = Point2::new(2, 2);
+        assert_eq!(tail.step_toward(tail), tail);
+        for head in [Point2::new(3, 3), Point2::new(1, 2), Point2::new(2, 1)] {
+            assert_eq!(tail.chebyshev(head), 1);
+            assert_eq!(tail.step_toward(head), head);
+        }
+        let head = Point2::new(4, 3);
+        assert_eq!(tail.step_toward(head).chebyshev(head), 1);
+    }
+
+    #[test]
+    fn rotate() {
+        let right = Direction::Right.step::<i32>();
+        assert_eq!(right.rotate_right(), Direction::Down.step());
+        assert_eq!(right.rotate_left(), Direction::Up.step());
+        assert_eq!(
+            Point2::new(2, 1).rotate_right().rotate_left(),
+            Point2::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn turns() {
+        let turns = [
+            (
+                Direction::Up,
+                Direction::Left,
+                Direction::Right,
+                Direction::Down,
+            ),
+            (
+                Direction::Right,
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+            ),
+            (
+                Direction::Down,
+                Direction::Right,
+                Direction::Left,
+                Direction::Up,
+            ),
+            (
+                Direction::Left,
+                Direction::Down,
+                Direction::Up,
+                Direction::Right,
+            ),
+        ];
+        for (direction, left, right, reverse) in turns {
+            assert_eq!(direction.turn_left(), left);
+            assert_eq!(direction.turn_right(), right);
+            assert_eq!(direction.reverse(), reverse);
+            // Turning matches rotating the step it takes
+            assert_eq!(left.step::<i32>(), direction.step::<i32>().rotate_left());
+            assert_eq!(right.step::<i32>(), direction.step::<i32>().rotate_right());
+            assert_eq!(reverse.step::<i32>(), -direction.step::<i32>());
+        }
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UD".parse::<Direction>().is_err());
+        assert_eq!(Point2::new(-1, 2).to_string(), "(-1, 2)");
+    }
+}
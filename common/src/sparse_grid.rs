@@ -0,0 +1,278 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! An unbounded grid that only stores the cells that are set, for puzzles that
+//! wander into negative or very large coordinates.
+//!
+//! Cells are addressed by [`Point2`], or an `(x, y)` tuple that converts into
+//! one, with y growing downwards as the grid is drawn. The grid keeps track of
+//! the box around its cells, so it can be drawn without choosing a window up front.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use crate::Point2;
+
+/// The smallest box holding a set of points, with both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    /// The top left corner.
+    pub min: Point2<i64>,
+    /// The bottom right corner.
+    pub max: Point2<i64>,
+}
+
+impl Bounds {
+    /// The box holding just `point`.
+    pub fn point(point: impl Into<Point2<i64>>) -> Bounds {
+        let point = point.into();
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// This box grown to hold `point` too.
+    pub fn expand(self, point: impl Into<Point2<i64>>) -> Bounds {
+        let point = point.into();
+        Bounds {
+            min: Point2::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            max: Point2::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        }
+    }
+
+    /// Whether `point` is inside the box.
+    pub fn contains(&self, point: impl Into<Point2<i64>>) -> bool {
+        let point = point.into();
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Number of columns in the box.
+    pub fn width(&self) -> u64 {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    /// Number of rows in the box.
+    pub fn height(&self) -> u64 {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+}
+
+/// A grid of cells at any point, where most cells are empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<i64>, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    /// An empty grid.
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Whether no cells are set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The box around every cell that is set, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Set the cell at `point`, returning what it held before.
+    pub fn insert(&mut self, point: impl Into<Point2<i64>>, value: T) -> Option<T> {
+        let point = point.into();
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.expand(point),
+            None => Bounds::point(point),
+        });
+        self.cells.insert(point, value)
+    }
+
+    /// Clear the cell at `point`, returning what it held.
+    pub fn remove(&mut self, point: impl Into<Point2<i64>>) -> Option<T> {
+        let point = point.into();
+        let value = self.cells.remove(&point)?;
+        // Only a cell on the edge of the box can make it smaller
+        let bounds = self.bounds.unwrap();
+        if point.x == bounds.min.x
+            || point.x == bounds.max.x
+            || point.y == bounds.min.y
+            || point.y == bounds.max.y
+        {
+            self.bounds = self
+                .cells
+                .keys()
+                .fold(None, |bounds: Option<Bounds>, &point| {
+                    Some(bounds.map_or(Bounds::point(point), |bounds| bounds.expand(point)))
+                });
+        }
+        Some(value)
+    }
+
+    /// The cell at `point`, if it is set.
+    pub fn get(&self, point: impl Into<Point2<i64>>) -> Option<&T> {
+        self.cells.get(&point.into())
+    }
+
+    /// The cell at `point` to change, if it is set.
+    pub fn get_mut(&mut self, point: impl Into<Point2<i64>>) -> Option<&mut T> {
+        self.cells.get_mut(&point.into())
+    }
+
+    /// Whether the cell at `point` is set.
+    pub fn contains(&self, point: impl Into<Point2<i64>>) -> bool {
+        self.cells.contains_key(&point.into())
+    }
+
+    /// Every cell that is set with its point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, &T)> + '_ {
+        self.cells.iter().map(|(&point, cell)| (point, cell))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// The cells inside `bounds`, for drawing part of a grid too big to draw whole.
+    pub fn crop(&self, bounds: Bounds) -> SparseGrid<T> {
+        self.iter()
+            .filter(|&(point, _)| bounds.contains(point))
+            .map(|(point, cell)| (point, cell.clone()))
+            .collect()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T, P: Into<Point2<i64>>> FromIterator<(P, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T, P: Into<Point2<i64>>> Extend<(P, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+/// The box around the cells, one line per row with `.` for the empty cells.
+/// Formatting options apply to each cell, as for [`Grid`](crate::Grid).
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        for y in bounds.min.y..=bounds.max.y {
+            if y > bounds.min.y {
+                writeln!(f)?;
+            }
+            for x in bounds.min.x..=bounds.max.x {
+                match self.get((x, y)) {
+                    Some(cell) => cell.fmt(f)?,
+                    None => ".".fmt(f)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        let bounds = Bounds::point((2, -1)).expand((-3, 4)).expand((0, 0));
+        assert_eq!(bounds.min, Point2::new(-3, -1));
+        assert_eq!(bounds.max, Point2::new(2, 4));
+        assert_eq!((bounds.width(), bounds.height()), (6, 6));
+        assert!(bounds.contains((-3, 4)));
+        assert!(bounds.contains((0, 0)));
+        assert!(!bounds.contains((3, 0)));
+        assert!(!bounds.contains((0, -2)));
+    }
+
+    // The box follows the cells as they are set and cleared
+    #[test]
+    fn insert_and_remove() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert((1, 1), 'a');
+        grid.insert((-2, 3), 'b');
+        grid.insert((0, 2), 'c');
+        // A point and the tuple it converts from are the same cell
+        assert_eq!(grid.get(Point2::new(1, 1)), Some(&'a'));
+        assert_eq!(grid.bounds().unwrap().min, Point2::new(-2, 1));
+        assert_eq!(grid.bounds().unwrap().max, Point2::new(1, 3));
+        assert_eq!(grid.insert((0, 2), 'd'), Some('c'));
+
+        // Clearing a cell inside the box leaves it be, one on the edge shrinks it
+        assert_eq!(grid.remove((0, 2)), Some('d'));
+        assert_eq!(grid.bounds().unwrap().min, Point2::new(-2, 1));
+        assert_eq!(grid.remove((-2, 3)), Some('b'));
+        assert_eq!(grid.bounds(), Some(Bounds::point((1, 1))));
+        assert_eq!(grid.remove((-2, 3)), None);
+        grid.remove((1, 1));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn crop() {
+        let grid = (-3..=3).map(|i| ((i, i), i)).collect::<SparseGrid<i64>>();
+        let cropped = grid.crop(Bounds::point((-1, -1)).expand((1, 2)));
+        assert_eq!(cropped.len(), 3);
+        assert_eq!(cropped.get((-1, -1)), Some(&-1));
+        assert!(!cropped.contains((2, 2)));
+        assert_eq!(
+            cropped.bounds(),
+            Some(Bounds::point((-1, -1)).expand((1, 1)))
+        );
+    }
+
+    #[test]
+    fn extend() {
+        let mut grid = SparseGrid::new();
+        grid.extend([((0, 0), '#'), ((5, -5), '#')]);
+        grid.extend([((0, 0), 'o')]);
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get((0, 0)), Some(&'o'));
+        assert_eq!(grid.bounds(), Some(Bounds::point((0, -5)).expand((5, 0))));
+    }
+
+    // Drawing starts from the top left of the box, wherever it is
+    #[test]
+    fn display_negative() {
+        let mut grid = SparseGrid::new();
+        grid.insert((-2, -1), '#');
+        grid.insert((0, 1), 'o');
+        assert_eq!(grid.to_string(), "#..\n...\n..o");
+        assert_eq!(SparseGrid::<char>::new().to_string(), "");
+        let grid = [((-1, 0), 7), ((1, 0), 10)]
+            .into_iter()
+            .collect::<SparseGrid<i32>>();
+        assert_eq!(format!("{:>3}", grid), "  7  . 10");
+    }
+}
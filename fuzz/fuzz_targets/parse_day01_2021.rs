@@ -0,0 +1,16 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! 2021 day 1's parser returns a `ParseError` for malformed input rather than panicking.
+
+#![no_main]
+
+use aoc_2021::day01::Day01;
+use aoc_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day01::parse(input);
+});
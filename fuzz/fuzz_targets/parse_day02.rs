@@ -0,0 +1,16 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Day 2's parser returns a `ParseError` for malformed input rather than panicking.
+
+#![no_main]
+
+use aoc_2022::day02::Day02;
+use aoc_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day02::parse(input);
+});
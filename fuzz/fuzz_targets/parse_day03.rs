@@ -0,0 +1,16 @@
+// Copyright (c) 2022 David Chan
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Day 3's parser returns a `ParseError` for malformed input rather than panicking.
+
+#![no_main]
+
+use aoc_2022::day03::Day03;
+use aoc_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day03::parse(input);
+});